@@ -0,0 +1,84 @@
+//! Compares building a wide view with `LifeRefBuilder` against chaining `add_ref` and `add_mut` on tuples.
+//!
+//! Run with `cargo bench --bench builder`.
+use borrow_as::builder::LifeRefBuilder;
+use borrow_as::{LifeRef, Mut, Ref};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERS: u32 = 1_000_000;
+
+struct View {
+    a: Ref<u64>,
+    b: Mut<u64>,
+    c: Ref<u64>,
+    d: Mut<u64>,
+    e: Ref<u64>,
+    f: Mut<u64>,
+    g: Ref<u64>,
+    h: Mut<u64>,
+}
+
+impl View {
+    fn sum(&self) -> u64 {
+        *self.a + self.b.get() + *self.c + self.d.get() + *self.e + self.f.get() + *self.g + self.h.get()
+    }
+}
+
+fn tuple(src: &mut [u64; 8]) -> u64 {
+    let [a, b, c, d, e, f, g, h] = src;
+    let view = LifeRef::
+        wrap_ref(black_box(&*a))
+        .add_mut(black_box(b))
+        .add_ref(black_box(&*c))
+        .add_mut(black_box(d))
+        .add_ref(black_box(&*e))
+        .add_mut(black_box(f))
+        .add_ref(black_box(&*g))
+        .add_mut(black_box(h))
+        .map_life(|(a, b, c, d, e, f, g, h)| View { a, b, c, d, e, f, g, h });
+    view.sum()
+}
+
+fn builder(src: &mut [u64; 8]) -> u64 {
+    let [a, b, c, d, e, f, g, h] = src;
+    let view = LifeRefBuilder::<8>::new()
+        .add_ref(black_box(&*a))
+        .add_mut(black_box(b))
+        .add_ref(black_box(&*c))
+        .add_mut(black_box(d))
+        .add_ref(black_box(&*e))
+        .add_mut(black_box(f))
+        .add_ref(black_box(&*g))
+        .add_mut(black_box(h))
+        .map_life(|[a, b, c, d, e, f, g, h]| unsafe {
+            View {
+                a: a.into_ref(),
+                b: b.into_mut(),
+                c: c.into_ref(),
+                d: d.into_mut(),
+                e: e.into_ref(),
+                f: f.into_mut(),
+                g: g.into_ref(),
+                h: h.into_mut(),
+            }
+        });
+    view.sum()
+}
+
+fn bench(name: &str, f: fn(&mut [u64; 8]) -> u64) -> Duration {
+    let mut src = [1, 2, 3, 4, 5, 6, 7, 8];
+    let start = Instant::now();
+    for _ in 0..ITERS {
+        black_box(f(black_box(&mut src)));
+    }
+    let elapsed = start.elapsed();
+    println!("{:<8} {:>8.2} ns/iter", name, elapsed.as_nanos() as f64 / f64::from(ITERS));
+    elapsed
+}
+
+fn main() {
+    assert_eq!(tuple(&mut [1; 8]), builder(&mut [1; 8]));
+    bench("tuple", tuple);
+    bench("builder", builder);
+}
@@ -0,0 +1,438 @@
+//! # Borrow As Derive
+//! Derive macros for `borrow_as`. See `borrow_as::BorrowAs`, `borrow_as::BorrowFields`, `borrow_as::FromLifeTuple`,
+//! `borrow_as::ViewKey` and `borrow_as::CopyOut` for usage.
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Ident, Token, Type};
+
+/// Maximum number of fields in a single view, limited by tuple support of `LifeRef`.
+const MAX_FIELDS: usize = 16;
+
+/// Single `View = ref method` entry of `#[borrow_as(...)]` field attribute.
+struct Entry {
+    view: Ident,
+    mutable: bool,
+    method: Option<Ident>,
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let view = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let lookahead = input.lookahead1();
+        let mutable = if lookahead.peek(Token![ref]) {
+            input.parse::<Token![ref]>()?;
+            false
+        }
+        else if lookahead.peek(Token![mut]) {
+            input.parse::<Token![mut]>()?;
+            true
+        }
+        else {
+            return Err(lookahead.error());
+        };
+        let method = if input.peek(Ident) {
+            Some(input.parse()?)
+        }
+        else {
+            None
+        };
+        Ok(Self { view, mutable, method })
+    }
+}
+
+/// Field borrowed by a view.
+struct ViewField {
+    field: Ident,
+    mutable: bool,
+    method: Option<Ident>,
+}
+
+/// View with its fields in declaration order.
+struct View {
+    name: Ident,
+    fields: Vec<ViewField>,
+}
+
+/// Derives split-borrow accessors. See `borrow_as::BorrowAs` for usage.
+#[proc_macro_derive(BorrowAs, attributes(borrow_as))]
+pub fn derive_borrow_as(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_borrow_as(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_borrow_as(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "BorrowAs supports only structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "BorrowAs supports only structs")),
+    };
+
+    let mut views: Vec<View> = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.clone().expect("named field");
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("borrow_as")) {
+            let entries = attr.parse_args_with(Punctuated::<Entry, Token![,]>::parse_terminated)?;
+            for entry in entries {
+                let view = match views.iter_mut().position(|v| v.name == entry.view) {
+                    Some(i) => &mut views[i],
+                    None => {
+                        views.push(View { name: entry.view.clone(), fields: Vec::new() });
+                        views.last_mut().unwrap()
+                    }
+                };
+                if view.fields.iter().any(|f| f.field == field_ident) {
+                    let msg = format!("field `{}` is borrowed more than once in view `{}`", field_ident, view.name);
+                    return Err(Error::new_spanned(&entry.view, msg));
+                }
+                view.fields.push(ViewField {
+                    field: field_ident.clone(),
+                    mutable: entry.mutable,
+                    method: entry.method,
+                });
+            }
+        }
+    }
+
+    check_mutable_overlap(&views)?;
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let methods = views
+        .iter()
+        .map(|view| expand_view(vis, view))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
+
+/// Rejects fields which are borrowed mutably by more than one view.
+fn check_mutable_overlap(views: &[View]) -> syn::Result<()> {
+    for (i, view) in views.iter().enumerate() {
+        for field in view.fields.iter().filter(|f| f.mutable) {
+            let other = views[i + 1..]
+                .iter()
+                .find(|v| v.fields.iter().any(|f| f.mutable && f.field == field.field));
+            if let Some(other) = other {
+                let msg = format!(
+                    "field `{}` is borrowed mutably in both views `{}` and `{}`",
+                    field.field, view.name, other.name,
+                );
+                return Err(Error::new_spanned(&other.name, msg));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn expand_view(vis: &syn::Visibility, view: &View) -> syn::Result<TokenStream2> {
+    if view.fields.len() > MAX_FIELDS {
+        let msg = format!("view `{}` borrows more than {} fields", view.name, MAX_FIELDS);
+        return Err(Error::new_spanned(&view.name, msg));
+    }
+
+    let name = &view.name;
+    let any_mut = view.fields.iter().any(|f| f.mutable);
+    let (getter, receiver) = if any_mut {
+        (format_ident!("get_{}_mut", snake_case(name)), quote!(&mut self))
+    }
+    else {
+        (format_ident!("get_{}", snake_case(name)), quote!(&self))
+    };
+
+    let idents: Vec<_> = view.fields.iter().map(|f| &f.field).collect();
+    let chain = view.fields.iter().enumerate().map(|(i, f)| {
+        let field = &f.field;
+        let arg = match (&f.method, f.mutable) {
+            (Some(method), _) => quote!(#field.#method()),
+            (None, true) => quote!(#field),
+            (None, false) => quote!(&*#field),
+        };
+        let method = match (i, f.mutable) {
+            (0, false) => quote!(::borrow_as::LifeRef::wrap_ref),
+            (0, true) => quote!(::borrow_as::LifeRef::wrap_mut),
+            (_, false) => quote!(.add_ref),
+            (_, true) => quote!(.add_mut),
+        };
+        quote!(#method(#arg))
+    });
+
+    Ok(quote! {
+        #vis fn #getter(#receiver) -> ::borrow_as::LifeRef<'_, #name> {
+            let Self { #(#idents,)* .. } = self;
+            #(#chain)*
+                .map_life(|(#(#idents,)*)| #name { #(#idents,)* })
+        }
+    })
+}
+
+fn snake_case(ident: &Ident) -> Ident {
+    let mut s = String::new();
+    for (i, c) in ident.to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                s.push('_');
+            }
+            s.extend(c.to_lowercase());
+        }
+        else {
+            s.push(c);
+        }
+    }
+    Ident::new(&s, Span::call_site())
+}
+
+/// Derives `BorrowFields` visiting every `Ref` and `Mut` field. See `borrow_as::BorrowFields` for usage.
+#[proc_macro_derive(BorrowFields)]
+pub fn derive_borrow_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_borrow_fields(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_borrow_fields(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "BorrowFields supports only structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "BorrowFields supports only structs")),
+    };
+
+    let borrowed: Vec<_> = fields
+        .iter()
+        .filter(|f| is_borrow(&f.ty))
+        .map(|f| (f.ident.as_ref().expect("named field"), &f.ty))
+        .collect();
+
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
+    generics.params.push(parse_quote!(__V));
+    {
+        let where_clause = generics.make_where_clause();
+        for (_, ty) in &borrowed {
+            where_clause.predicates.push(parse_quote!(__V: ::borrow_as::Visit<#ty>));
+        }
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let visits = borrowed.iter().map(|(field, _)| {
+        let label = field.to_string();
+        quote!(::borrow_as::Visit::visit(visitor, #label, &self.#field);)
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::borrow_as::BorrowFields<__V> for #name #ty_generics #where_clause {
+            fn visit_fields(&self, visitor: &mut __V) {
+                #(#visits)*
+            }
+        }
+    })
+}
+
+/// Derives `FromLifeTuple` taking fields in declaration order. See `borrow_as::FromLifeTuple` for usage.
+#[proc_macro_derive(FromLifeTuple)]
+pub fn derive_from_life_tuple(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_life_tuple(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_from_life_tuple(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "FromLifeTuple supports only structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "FromLifeTuple supports only structs")),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().expect("named field")).collect();
+    let types = fields.iter().map(|f| &f.ty);
+
+    Ok(quote! {
+        impl #impl_generics ::borrow_as::FromLifeTuple for #name #ty_generics #where_clause {
+            type Tuple = (#(#types,)*);
+
+            fn from_life_tuple((#(#idents,)*): Self::Tuple) -> Self {
+                Self { #(#idents,)* }
+            }
+        }
+    })
+}
+
+/// Derives `PartialEq`, `Eq` and `Hash` over `Ref` and `Mut` fields. See `borrow_as::ViewKey` for usage.
+#[proc_macro_derive(ViewKey, attributes(view_key))]
+pub fn derive_view_key(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_view_key(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_view_key(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "ViewKey supports only structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "ViewKey supports only structs")),
+    };
+
+    let mut all = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("view_key")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("all") {
+                all = true;
+                Ok(())
+            }
+            else {
+                Err(meta.error("expected `all`"))
+            }
+        })?;
+    }
+
+    let keys: Vec<_> = fields
+        .iter()
+        .filter(|f| all || is_borrow(&f.ty))
+        .map(|f| (f.ident.as_ref().expect("named field"), &f.ty))
+        .collect();
+
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let bounded = |bound: TokenStream2| {
+        let mut generics = input.generics.clone();
+        let where_clause = generics.make_where_clause();
+        for (_, ty) in &keys {
+            where_clause.predicates.push(parse_quote!(#ty: #bound));
+        }
+        generics
+    };
+    let eq_generics = bounded(quote!(::core::cmp::PartialEq));
+    let (eq_impl, _, eq_where) = eq_generics.split_for_impl();
+    let total_generics = bounded(quote!(::core::cmp::Eq));
+    let (total_impl, _, total_where) = total_generics.split_for_impl();
+    let hash_generics = bounded(quote!(::core::hash::Hash));
+    let (hash_impl, _, hash_where) = hash_generics.split_for_impl();
+    let idents: Vec<_> = keys.iter().map(|(field, _)| field).collect();
+
+    Ok(quote! {
+        impl #eq_impl ::core::cmp::PartialEq for #name #ty_generics #eq_where {
+            fn eq(&self, other: &Self) -> bool {
+                true #(&& self.#idents == other.#idents)*
+            }
+        }
+
+        impl #total_impl ::core::cmp::Eq for #name #ty_generics #total_where {}
+
+        impl #hash_impl ::core::hash::Hash for #name #ty_generics #hash_where {
+            fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                #(::core::hash::Hash::hash(&self.#idents, state);)*
+            }
+        }
+    })
+}
+
+/// Derives `CopyOut` together with owned struct named by `#[copy_out(name = ...)]`. See `borrow_as::CopyOut` for usage.
+#[proc_macro_derive(CopyOut, attributes(copy_out))]
+pub fn derive_copy_out(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_copy_out(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_copy_out(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "CopyOut supports only structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "CopyOut supports only structs")),
+    };
+
+    let mut owned = None;
+    let mut derives = Vec::new();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("copy_out")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                owned = Some(meta.value()?.parse::<Ident>()?);
+                Ok(())
+            }
+            else if meta.path.is_ident("derive") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                derives.extend(Punctuated::<syn::Path, Token![,]>::parse_terminated(&content)?);
+                Ok(())
+            }
+            else {
+                Err(meta.error("expected `name` or `derive`"))
+            }
+        })?;
+    }
+    let owned = owned.ok_or_else(|| Error::new_spanned(&input.ident, "CopyOut requires `#[copy_out(name = ...)]`"))?;
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let owned_fields = fields.iter().map(|f| {
+        let (fvis, field, ty) = (&f.vis, &f.ident, &f.ty);
+        if is_borrow(ty) {
+            quote!(#fvis #field: <#ty as ::borrow_as::CopyOut>::Owned)
+        }
+        else {
+            quote!(#fvis #field: #ty)
+        }
+    });
+    let copies = fields.iter().map(|f| {
+        let field = &f.ident;
+        if is_borrow(&f.ty) {
+            quote!(#field: ::borrow_as::CopyOut::copy_out(&self.#field))
+        }
+        else {
+            quote!(#field: self.#field)
+        }
+    });
+    let doc = format!("Owned counterpart of `{}`, produced by `CopyOut`.", name);
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Copy, Clone, #(#derives,)*)]
+        #vis struct #owned #impl_generics #where_clause {
+            #(#owned_fields,)*
+        }
+
+        impl #impl_generics ::borrow_as::CopyOut for #name #ty_generics #where_clause {
+            type Owned = #owned #ty_generics;
+
+            fn copy_out(&self) -> Self::Owned {
+                #owned { #(#copies,)* }
+            }
+        }
+    })
+}
+
+/// Checks whether type is `Ref<_>` or `Mut<_>`, possibly with a path prefix.
+fn is_borrow(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) if p.qself.is_none() => p.path.segments.last()
+            .is_some_and(|s| s.ident == "Ref" || s.ident == "Mut"),
+        _ => false,
+    }
+}
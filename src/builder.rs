@@ -0,0 +1,283 @@
+//! Array-based alternative to chaining `add_ref` and `add_mut` on tuples.
+//!
+//! `LifeRefBuilder` collects up to `N` references into type-erased slots, so wide views don't produce
+//! long tuple types and aren't limited to 16 elements. Type information is recovered with unsafe
+//! `Slot::into_ref` and `Slot::into_mut` when finalizing with `map_life`, and it's up to the caller to name the added type.
+//!
+//! `ImmutableBuilder` chains only immutable references, producing `RefView` which can't hold `Mut` elements.
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use crate::{tuple::Append, LifeRef, Mut, Ref};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Kind {
+    Empty,
+    Ref,
+    Mut,
+}
+
+/// Type-erased immutable or mutable reference collected by `LifeRefBuilder`.
+#[derive(Copy, Clone, Debug)]
+pub struct Slot {
+    ptr: *const (),
+    kind: Kind,
+}
+
+impl Slot {
+    const EMPTY: Self = Self {
+        ptr: core::ptr::null(),
+        kind: Kind::Empty,
+    };
+
+    /// Checks whether nothing was added into this slot.
+    pub fn is_empty(&self) -> bool {
+        self.kind == Kind::Empty
+    }
+
+    /// Checks whether this slot holds mutable reference.
+    pub fn is_mut(&self) -> bool {
+        self.kind == Kind::Mut
+    }
+
+    /// Converts slot back into immutable reference. Mutable reference is downgraded.
+    ///
+    /// # Safety
+    /// `U` must be the type of added value. The result must not outlive `LifeRef` it's put into.
+    /// If slot holds mutable reference, no `Mut` made from it by `into_mut` may be used while the result is.
+    ///
+    /// # Panics
+    /// Panics if slot is empty.
+    pub unsafe fn into_ref<U>(self) -> Ref<U> {
+        assert!(!self.is_empty(), "slot is empty");
+        Ref::new(self.ptr as *const U)
+    }
+
+    /// Converts slot back into mutable reference.
+    ///
+    /// # Safety
+    /// `U` must be the type of added value. The result must not outlive `LifeRef` it's put into, and no `Ref`
+    /// made from this slot by `into_ref` may be used while the result is.
+    ///
+    /// # Panics
+    /// Panics if slot doesn't hold mutable reference.
+    pub unsafe fn into_mut<U>(self) -> Mut<U> {
+        assert!(self.is_mut(), "slot doesn't hold mutable reference");
+        Mut::new(self.ptr as *const UnsafeCell<U>)
+    }
+}
+
+/// Builder collecting up to `N` references sharing lifetime `'a` into type-erased slots.
+///
+/// Only `Sized` values can be added, as slots don't keep pointer metadata. Values borrowing other data are fine,
+/// as slots don't keep type information either.
+/// # Example
+/// ```
+/// use borrow_as::{builder::LifeRefBuilder, Mut, Ref};
+/// struct View {
+///     s: Ref<String>,
+///     n: Mut<u32>,
+///     x: Ref<i8>,
+/// }
+///
+/// let s = String::from("Referenced");
+/// let mut n = 0u32;
+/// let x: i8 = 1;
+/// let view = LifeRefBuilder::<3>::new()
+///     .add_ref(&s)
+///     .add_mut(&mut n)
+///     .add_ref(&x)
+///     .map_life(|[s, n, x]| unsafe {
+///         View { s: s.into_ref(), n: n.into_mut(), x: x.into_ref() }
+///     });
+/// view.n.set(view.s.len() as u32);
+/// assert_eq!(view.x, &1);
+/// assert_eq!(n, 10);
+/// ```
+/// Adding past `N` references panics:
+/// ```should_panic
+/// let (a, b) = (1, 2);
+/// borrow_as::builder::LifeRefBuilder::<1>::new().add_ref(&a).add_ref(&b);
+/// ```
+/// Adding value which borrows other data:
+/// ```
+/// use borrow_as::{builder::LifeRefBuilder, Ref};
+/// let s = String::from("Referenced");
+/// let words: Vec<&str> = s.split('e').collect();
+/// let view = LifeRefBuilder::<1>::new()
+///     .add_ref(&words)
+///     .map_life(|[w]| unsafe { (w.into_ref::<Vec<&str>>(),) });
+/// assert_eq!(view.0.len(), 5);
+/// ```
+#[derive(Debug)]
+pub struct LifeRefBuilder<'a, const N: usize> {
+    slots: [Slot; N],
+    len: usize,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, const N: usize> LifeRefBuilder<'a, N> {
+    /// Creates builder with every slot empty.
+    pub fn new() -> Self {
+        Self {
+            slots: [Slot::EMPTY; N],
+            len: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns number of references added so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether no reference was added yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn push(mut self, slot: Slot) -> Self {
+        assert!(self.len < N, "builder is full");
+        self.slots[self.len] = slot;
+        self.len += 1;
+        self
+    }
+
+    /// Puts immutable reference into the next slot.
+    ///
+    /// # Panics
+    /// Panics if all `N` slots are taken.
+    pub fn add_ref<U>(self, r: &'a U) -> Self {
+        self.push(Slot {
+            ptr: r as *const U as *const (),
+            kind: Kind::Ref,
+        })
+    }
+
+    /// Puts mutable reference into the next slot.
+    ///
+    /// # Panics
+    /// Panics if all `N` slots are taken.
+    pub fn add_mut<U>(self, r: &'a mut U) -> Self {
+        self.push(Slot {
+            ptr: r as *mut U as *const (),
+            kind: Kind::Mut,
+        })
+    }
+
+    /// Converts collected slots into `LifeRef` of another type. Slots past `len` are empty.
+    pub fn map_life<V>(self, f: impl FnOnce([Slot; N]) -> V) -> LifeRef<'a, V> {
+        LifeRef {
+            inner: f(self.slots),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<const N: usize> Default for LifeRefBuilder<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// `Ref` or tuple of up to 16 such elements, nested tuples included. It's sealed, so `Mut` can't be made to implement it.
+pub trait RefOnly: sealed::Sealed {}
+
+impl<T: ?Sized> sealed::Sealed for Ref<T> {}
+
+impl<T: ?Sized> RefOnly for Ref<T> {}
+
+/// `LifeRef` produced by `ImmutableBuilder`, consisting only of `Ref` elements.
+///
+/// Dereferences to `LifeRef`. Naming it with `Mut` element fails to compile:
+/// ```compile_fail
+/// use borrow_as::{builder::RefView, Mut, Ref};
+/// fn view(_: RefView<'_, (Ref<str>, Mut<u32>)>) {}
+/// ```
+#[derive(Debug)]
+pub struct RefView<'a, T: RefOnly>(LifeRef<'a, T>);
+
+impl<'a, T: RefOnly> RefView<'a, T> {
+    /// Converts into plain `LifeRef`, forgetting that it's fully shared.
+    pub fn into_life_ref(self) -> LifeRef<'a, T> {
+        self.0
+    }
+}
+
+impl<'a, T: RefOnly> Deref for RefView<'a, T> {
+    type Target = LifeRef<'a, T>;
+
+    fn deref(&self) -> &LifeRef<'a, T> {
+        &self.0
+    }
+}
+
+/// Builder chaining immutable references only, so the resulting view can't contain `Mut`.
+/// # Example
+/// ```
+/// use borrow_as::{builder::{ImmutableBuilder, RefView}, Ref};
+/// struct Data {
+///     a: String,
+///     b: u32,
+/// }
+///
+/// impl Data {
+///     fn view(&self) -> RefView<'_, (Ref<str>, Ref<u32>)> {
+///         ImmutableBuilder::wrap_ref(self.a.as_str()).add_ref(&self.b).build()
+///     }
+/// }
+///
+/// let d = Data { a: String::from("Referenced"), b: 1 };
+/// let v = d.view();
+/// assert_eq!(v.0, "Referenced");
+/// assert_eq!(v.1, &1);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ImmutableBuilder<'a, T> {
+    inner: LifeRef<'a, T>,
+}
+
+impl<'a> ImmutableBuilder<'a, ()> {
+    /// Creates builder with empty tuple.
+    pub const fn new() -> Self {
+        Self {
+            inner: LifeRef::empty(),
+        }
+    }
+}
+
+impl<'a, T: ?Sized> ImmutableBuilder<'a, (Ref<T>,)> {
+    /// Creates builder with single immutable reference.
+    pub const fn wrap_ref(r: &'a T) -> Self {
+        Self {
+            inner: LifeRef::wrap_ref(r),
+        }
+    }
+}
+
+impl<'a, T> ImmutableBuilder<'a, T> {
+    /// Extends inner tuple by one element which represents passed immutable reference. Supports extending up to 16 elements.
+    pub fn add_ref<U>(self, r: &'a U) -> ImmutableBuilder<'a, T::Output> where
+    T: Append<Ref<U>>,
+    U: 'a + ?Sized {
+        ImmutableBuilder {
+            inner: self.inner.add_ref(r),
+        }
+    }
+
+    /// Finishes building, returning view of immutable references.
+    pub fn build(self) -> RefView<'a, T> where
+    T: RefOnly {
+        RefView(self.inner)
+    }
+}
+
+impl Default for ImmutableBuilder<'_, ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
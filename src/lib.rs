@@ -119,309 +119,3549 @@
 //! assert_eq!(x.x, 9);
 //! assert_eq!((x.f)(), 8);
 #![cfg_attr(not(test), no_std)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::fmt;
-use core::ops::Deref;
+use core::ops::{Add, Deref, Index, RangeBounds, Sub};
 use core::borrow::Borrow;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::cell::Cell;
-use tuple_utils::Append;
+use core::mem::MaybeUninit;
+use core::pin::Pin;
+use core::cell::{Cell, UnsafeCell};
+use tuple_utils::Prepend;
+use tuple::Append;
+
+pub mod builder;
+pub mod tuple;
+
+/// Derives methods returning `LifeRef` views over struct fields.
+///
+/// Each field is marked with `#[borrow_as(View = ref)]` or `#[borrow_as(View = mut)]` for every view it belongs to,
+/// optionally followed by method name used to convert the field, e.g. `as_str` or `as_mut_slice`.
+/// View is a struct with the same field names of `Ref` or `Mut` types.
+/// For every view `get_view(&self)` is generated, or `get_view_mut(&mut self)` if any of its fields is mutable.
+///
+/// Borrowing field more than once in one view, or mutably in more than one view, is a compile error.
+/// # Example
+/// ```
+/// use borrow_as::*;
+///
+/// #[derive(BorrowAs)]
+/// struct X {
+///     #[borrow_as(A = ref as_str, B = ref as_str)]
+///     s: String,
+///     #[borrow_as(A = ref as_slice, C = mut as_mut_slice)]
+///     v: Vec<u128>,
+///     #[borrow_as(B = ref, C = mut)]
+///     i: i8,
+/// }
+///
+/// struct A {
+///     s: Ref<str>,
+///     v: Ref<[u128]>,
+/// }
+///
+/// struct B {
+///     s: Ref<str>,
+///     i: Ref<i8>,
+/// }
+///
+/// struct C {
+///     v: Mut<[u128]>,
+///     i: Mut<i8>,
+/// }
+///
+/// let mut x = X {
+///     s: String::from("Referenced"),
+///     v: vec![1, 2, 3],
+///     i: 0,
+/// };
+///
+/// let a = x.get_a();
+/// let b = x.get_b();
+/// assert_eq!(a.s, "Referenced");
+/// assert_eq!(a.v, [1, 2, 3]);
+/// assert_eq!(b.s, "Referenced");
+/// assert_eq!(b.i, &0);
+///
+/// let c = x.get_c_mut();
+/// c.i.set(1);
+/// c.v.as_slice_of_cells()[2].set(4);
+/// assert_eq!(x.i, 1);
+/// assert_eq!(x.v, [1, 2, 4]);
+/// ```
+/// ```compile_fail
+/// use borrow_as::*;
+///
+/// #[derive(BorrowAs)]
+/// struct X {
+///     #[borrow_as(A = mut, B = mut)]
+///     i: i8,
+/// }
+///
+/// struct A {
+///     i: Mut<i8>,
+/// }
+///
+/// struct B {
+///     i: Mut<i8>,
+/// }
+#[cfg(feature = "derive")]
+pub use borrow_as_derive::BorrowAs;
+
+/// Borrows listed fields of a struct into `LifeRef` of a tuple, without defining a view struct.
+///
+/// Each field is prefixed with `ref` or `mut`, producing `Ref` or `Mut` element in the same order.
+/// Fields are borrowed directly, so borrows are disjoint even through `&mut self`.
+/// # Example
+/// ```
+/// use borrow_as::*;
+/// struct X {
+///     s: String,
+///     v: Vec<u32>,
+///     n: usize,
+///     other: u8,
+/// }
+///
+/// impl X {
+///     fn count(&mut self) -> LifeRef<'_, (Ref<String>, Mut<usize>, Ref<Vec<u32>>)> {
+///         borrow_fields!(self, ref s, mut n, ref v)
+///     }
+/// }
+///
+/// let mut x = X { s: String::from("Referenced"), v: vec![1, 2, 3], n: 0, other: 0 };
+/// x.count().scope(|(s, n, v)| n.set(s.len() + v.len()));
+/// assert_eq!(x.n, 13);
+///
+/// let r = borrow_fields!(x, mut other, ref n);
+/// r.0.set(*r.1 as u8);
+/// assert_eq!(x.other, 13);
+#[macro_export]
+macro_rules! borrow_fields {
+    (@chain $obj:expr, $acc:expr,) => { $acc };
+    (@chain $obj:expr, $acc:expr, ref $field:ident, $($rest:tt)*) => {
+        $crate::borrow_fields!(@chain $obj, $acc.__add_ref_named(&$obj.$field, stringify!($field)), $($rest)*)
+    };
+    (@chain $obj:expr, $acc:expr, mut $field:ident, $($rest:tt)*) => {
+        $crate::borrow_fields!(@chain $obj, $acc.__add_mut_named(&mut $obj.$field, stringify!($field)), $($rest)*)
+    };
+    ($obj:expr, $($kind:tt $field:ident),+ $(,)?) => {
+        $crate::borrow_fields!(@chain $obj, $crate::LifeRef::empty(), $($kind $field,)+)
+    };
+}
+
+/// Visitor of a single `Ref` or `Mut` field, used by `BorrowFields`.
+pub trait Visit<F> {
+    /// Visits field named `name`.
+    fn visit(&mut self, name: &'static str, field: &F);
+}
+
+/// Struct with `Ref` and `Mut` fields which can be visited uniformly.
+///
+/// With `derive` feature it can be derived, visiting every `Ref` and `Mut` field in declaration order and skipping the rest.
+/// Tuples of up to 16 elements implement it as well, visiting every element.
+/// # Example
+/// ```
+/// use borrow_as::*;
+///
+/// struct C {
+///     v: Mut<[u128]>,
+///     i: Ref<i8>,
+///     f: Mut<Box<dyn Fn() -> i32>>,
+///     x: u32,
+/// }
+///
+/// impl<V> BorrowFields<V> for C where
+/// V: Visit<Mut<[u128]>> + Visit<Ref<i8>> + Visit<Mut<Box<dyn Fn() -> i32>>> {
+///     fn visit_fields(&self, visitor: &mut V) {
+///         visitor.visit("v", &self.v);
+///         visitor.visit("i", &self.i);
+///         visitor.visit("f", &self.f);
+///     }
+/// }
+///
+/// struct Reset;
+///
+/// impl Visit<Mut<[u128]>> for Reset {
+///     fn visit(&mut self, _: &'static str, field: &Mut<[u128]>) {
+///         field.iter_muts().for_each(|x| x.set(0));
+///     }
+/// }
+///
+/// impl Visit<Mut<Box<dyn Fn() -> i32>>> for Reset {
+///     fn visit(&mut self, _: &'static str, field: &Mut<Box<dyn Fn() -> i32>>) {
+///         field.set(Box::new(|| 0));
+///     }
+/// }
+///
+/// impl<T: ?Sized> Visit<Ref<T>> for Reset {
+///     fn visit(&mut self, _: &'static str, _: &Ref<T>) {}
+/// }
+///
+/// let mut v = vec![1, 2, 3];
+/// let i = 4;
+/// let mut f: Box<dyn Fn() -> i32> = Box::new(|| 5);
+/// let c = LifeRef::
+///     wrap_mut(v.as_mut_slice())
+///     .add_ref(&i)
+///     .add_mut(&mut f)
+///     .map_life(|(v, i, f)| C { v, i, f, x: 6 });
+/// c.visit_fields(&mut Reset);
+/// assert_eq!(c.x, 6);
+/// assert_eq!(v, [0, 0, 0]);
+/// assert_eq!(i, 4);
+/// assert_eq!(f(), 0);
+/// ```
+/// Same with derive:
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use borrow_as::*;
+///
+/// #[derive(BorrowFields)]
+/// struct View {
+///     s: Ref<str>,
+///     n: Mut<u32>,
+///     x: u32,
+/// }
+///
+/// struct Names(Vec<&'static str>);
+///
+/// impl<F> Visit<F> for Names {
+///     fn visit(&mut self, name: &'static str, _: &F) {
+///         self.0.push(name);
+///     }
+/// }
+///
+/// let s = String::from("Referenced");
+/// let mut n = 0;
+/// let view = LifeRef::wrap_ref(s.as_str()).add_mut(&mut n).map_life(|(s, n)| View { s, n, x: 1 });
+/// let mut names = Names(Vec::new());
+/// view.visit_fields(&mut names);
+/// assert_eq!(names.0, ["s", "n"]);
+/// assert_eq!(view.x, 1);
+/// # }
+pub trait BorrowFields<V> {
+    /// Visits every `Ref` and `Mut` field with `visitor`.
+    fn visit_fields(&self, visitor: &mut V);
+}
+
+#[cfg(feature = "derive")]
+pub use borrow_as_derive::BorrowFields;
+
+/// Visitor handling every `Ref` and `Mut` field generically, including unsized ones.
+///
+/// Every `LifeVisitor` is `Visit` for any `Ref` and `Mut`, so it can be passed to `visit_fields` of any `BorrowFields` type.
+/// # Example
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use borrow_as::*;
+///
+/// #[derive(BorrowFields)]
+/// struct View {
+///     s: Ref<str>,
+///     v: Mut<[u8]>,
+///     n: Mut<u32>,
+///     x: u32,
+/// }
+///
+/// #[derive(Default)]
+/// struct Count {
+///     refs: usize,
+///     muts: usize,
+/// }
+///
+/// impl LifeVisitor for Count {
+///     fn visit_ref<T: ?Sized>(&mut self, _: &Ref<T>) {
+///         self.refs += 1;
+///     }
+///
+///     fn visit_mut<T: ?Sized>(&mut self, _: &Mut<T>) {
+///         self.muts += 1;
+///     }
+/// }
+///
+/// let s = String::from("Referenced");
+/// let (mut v, mut n) = (vec![1, 2], 3);
+/// let view = LifeRef::wrap_ref(s.as_str())
+///     .add_mut(v.as_mut_slice())
+///     .add_mut(&mut n)
+///     .map_life(|(s, v, n)| View { s, v, n, x: 4 });
+/// let mut count = Count::default();
+/// view.visit_fields(&mut count);
+/// assert_eq!((count.refs, count.muts), (1, 2));
+/// assert_eq!(view.x, 4);
+/// # }
+pub trait LifeVisitor {
+    /// Visits `Ref` field.
+    fn visit_ref<T: ?Sized>(&mut self, r: &Ref<T>);
+    /// Visits `Mut` field.
+    fn visit_mut<T: ?Sized>(&mut self, m: &Mut<T>);
+}
+
+impl<V: LifeVisitor + ?Sized, T: ?Sized> Visit<Ref<T>> for V {
+    fn visit(&mut self, _: &'static str, field: &Ref<T>) {
+        self.visit_ref(field);
+    }
+}
+
+impl<V: LifeVisitor + ?Sized, T: ?Sized> Visit<Mut<T>> for V {
+    fn visit(&mut self, _: &'static str, field: &Mut<T>) {
+        self.visit_mut(field);
+    }
+}
+
+/// Struct which can be built from a tuple of its fields, used by `LifeRef::build` instead of a closure passed to `map_life`.
+///
+/// With `derive` feature it can be derived, taking every field in declaration order.
+/// Building from a tuple of different shape fails to compile.
+/// # Example
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use borrow_as::*;
+///
+/// #[derive(FromLifeTuple)]
+/// struct C {
+///     v: Mut<[u128]>,
+///     i: Ref<i8>,
+///     f: Mut<Box<dyn Fn() -> i32>>,
+/// }
+///
+/// let mut v = vec![1, 2, 3];
+/// let i = 4;
+/// let mut f: Box<dyn Fn() -> i32> = Box::new(|| 5);
+/// let c = LifeRef::
+///     wrap_mut(v.as_mut_slice())
+///     .add_ref(&i)
+///     .add_mut(&mut f)
+///     .build::<C>();
+/// c.v.as_slice_of_cells()[0].set(*c.i as u128);
+/// c.f.set(Box::new(|| 6));
+/// assert_eq!(v, [4, 2, 3]);
+/// assert_eq!(f(), 6);
+/// # }
+/// ```
+#[cfg_attr(feature = "derive", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use borrow_as::*;
+///
+/// #[derive(FromLifeTuple)]
+/// struct A {
+///     s: Ref<str>,
+///     v: Ref<[u128]>,
+/// }
+///
+/// let (s, v) = (String::new(), vec![0]);
+/// let a = LifeRef::wrap_ref(s.as_str()).add_ref(v.as_slice()).add_ref(&0).build::<A>();
+pub trait FromLifeTuple {
+    /// Tuple of field types in order expected by `from_life_tuple`.
+    type Tuple;
+    /// Builds struct from its fields.
+    fn from_life_tuple(t: Self::Tuple) -> Self;
+}
+
+#[cfg(feature = "derive")]
+pub use borrow_as_derive::FromLifeTuple;
+
+/// View which can be cloned into owned values, escaping the lifetime of borrow.
+///
+/// `Ref` fields are cloned with `ToOwned`, and `Mut` fields are snapshotted with `Clone` on the value taken out of the cell.
+/// Tuples of up to 16 elements implement it elementwise, and `LifeRef` forwards to inner value.
+/// # Example
+/// ```
+/// use borrow_as::*;
+/// struct A {
+///     s: Ref<str>,
+///     v: Ref<[u128]>,
+/// }
+///
+/// impl ToOwnedView for A {
+///     type Owned = (String, Vec<u128>);
+///
+///     fn to_owned_view(&self) -> Self::Owned {
+///         (self.s.to_owned_view(), self.v.to_owned_view())
+///     }
+/// }
+///
+/// let owned = {
+///     let s = String::from("Referenced");
+///     let v = vec![1, 2, 3];
+///     let a = LifeRef::wrap_ref(s.as_str()).add_ref(v.as_slice()).map_life(|(s, v)| A { s, v });
+///     a.to_owned_view()
+/// };
+/// assert_eq!(owned, (String::from("Referenced"), vec![1, 2, 3]));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait ToOwnedView {
+    /// Owned counterpart of the view.
+    type Owned;
+    /// Clones view into owned values.
+    fn to_owned_view(&self) -> Self::Owned;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + alloc::borrow::ToOwned> ToOwnedView for Ref<T> {
+    type Owned = T::Owned;
+
+    fn to_owned_view(&self) -> T::Owned {
+        self.deref().to_owned()
+    }
+}
+
+/// Snapshots current value.
+/// # Example
+/// ```
+/// use borrow_as::ToOwnedView;
+/// let mut s = String::from("Old");
+/// let r = borrow_as::LifeRef::wrap_mut(&mut s);
+/// let owned = r.to_owned_view();
+/// r.0.set(String::from("New"));
+/// assert_eq!(owned, (String::from("Old"),));
+/// assert_eq!(s, "New");
+/// ```
+/// `Clone` runs on the value taken out of the cell, so it can't observe or interfere with writes through other handles.
+#[cfg(feature = "alloc")]
+impl<T: Clone + Default> ToOwnedView for Mut<T> {
+    type Owned = T;
+
+    fn to_owned_view(&self) -> T {
+        self.clone_out()
+    }
+}
+
+/// Snapshots current string.
+#[cfg(feature = "alloc")]
+impl ToOwnedView for Mut<str> {
+    type Owned = alloc::string::String;
+
+    fn to_owned_view(&self) -> alloc::string::String {
+        unsafe { self.get() }.into()
+    }
+}
+
+/// Snapshots current elements by copying them one by one.
+/// # Example
+/// ```
+/// use borrow_as::ToOwnedView;
+/// let mut v = [1, 2, 3];
+/// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+/// let owned = r.0.to_owned_view();
+/// r.0.iter_muts().for_each(|x| x.set(0));
+/// assert_eq!(owned, [1, 2, 3]);
+#[cfg(feature = "alloc")]
+impl<T: Copy> ToOwnedView for Mut<[T]> {
+    type Owned = alloc::vec::Vec<T>;
+
+    fn to_owned_view(&self) -> alloc::vec::Vec<T> {
+        self.as_slice_of_cells().iter().map(Cell::get).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ToOwnedView> ToOwnedView for LifeRef<'_, T> {
+    type Owned = T::Owned;
+
+    fn to_owned_view(&self) -> T::Owned {
+        self.inner.to_owned_view()
+    }
+}
+
+/// View which can be copied into owned values, escaping the lifetime of borrow.
+///
+/// `Ref` and `Mut` fields are read by `Copy`. Tuples of up to 16 elements implement it elementwise.
+///
+/// With `derive` feature it can be derived, generating owned struct named by `#[copy_out(name = ...)]`
+/// with the same fields, where `Ref<T>` and `Mut<T>` become `T` and other fields are copied as is.
+/// Traits to derive for it are listed in `#[copy_out(derive(...))]`.
+/// # Example
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use borrow_as::*;
+///
+/// #[derive(CopyOut)]
+/// #[copy_out(name = OwnedB, derive(Debug, PartialEq))]
+/// struct B {
+///     i: Mut<i8>,
+///     x: u32,
+///     r: Ref<[u8; 2]>,
+/// }
+///
+/// let owned = {
+///     let mut i = 1;
+///     let r = [2, 3];
+///     let b = LifeRef::wrap_mut(&mut i).add_ref(&r).map_life(|(i, r)| B { i, x: 4, r });
+///     b.i.set(5);
+///     b.copied()
+/// };
+/// assert_eq!(owned, OwnedB { i: 5, x: 4, r: [2, 3] });
+/// # }
+pub trait CopyOut {
+    /// Owned counterpart of the view.
+    type Owned;
+    /// Copies view into owned values.
+    fn copy_out(&self) -> Self::Owned;
+}
+
+impl<T: Copy> CopyOut for Ref<T> {
+    type Owned = T;
+
+    fn copy_out(&self) -> T {
+        **self
+    }
+}
+
+impl<T: Copy> CopyOut for Mut<T> {
+    type Owned = T;
+
+    fn copy_out(&self) -> T {
+        Cell::get(self)
+    }
+}
+
+#[cfg(feature = "derive")]
+pub use borrow_as_derive::CopyOut;
+
+/// View which can be converted into std references borrowing it: `&T` for `Ref<T>` and `&Cell<T>` for `Mut<T>`.
+///
+/// Tuples of up to 16 elements implement it elementwise.
+pub trait AsStdRefs<'r> {
+    /// Std counterpart of the view.
+    type Output;
+    /// Converts view into std references.
+    fn as_std_refs(&'r self) -> Self::Output;
+}
+
+impl<'r, T: ?Sized + 'r> AsStdRefs<'r> for Ref<T> {
+    type Output = &'r T;
+
+    fn as_std_refs(&'r self) -> &'r T {
+        self
+    }
+}
+
+impl<'r, T: ?Sized + 'r> AsStdRefs<'r> for Mut<T> {
+    type Output = &'r Cell<T>;
+
+    fn as_std_refs(&'r self) -> &'r Cell<T> {
+        self
+    }
+}
+
+/// Derives `PartialEq`, `Eq` and `Hash` comparing and hashing referenced values of `Ref` and `Mut` fields,
+/// so a view can be used as a key of hashed collection.
+///
+/// Other fields are skipped unless struct is marked with `#[view_key(all)]`.
+/// Note: `Mut` fields can change while in collection, breaking it, so prefer `Ref` fields or `Mut::freeze` for keys.
+/// # Example
+/// ```
+/// use std::collections::HashSet;
+/// use borrow_as::*;
+///
+/// #[derive(ViewKey)]
+/// struct A {
+///     s: Ref<str>,
+///     v: Ref<[u128]>,
+///     tag: u8,
+/// }
+///
+/// #[derive(ViewKey)]
+/// #[view_key(all)]
+/// struct Tagged {
+///     s: Ref<str>,
+///     tag: u8,
+/// }
+///
+/// fn a<'a>(s: &'a str, v: &'a [u128], tag: u8) -> LifeRef<'a, A> {
+///     LifeRef::wrap_ref(s).add_ref(v).map_life(|(s, v)| A { s, v, tag })
+/// }
+///
+/// fn tagged(s: &str, tag: u8) -> LifeRef<'_, Tagged> {
+///     LifeRef::wrap_ref(s).map_life(|(s,)| Tagged { s, tag })
+/// }
+///
+/// let (s1, v1) = (String::from("Referenced"), vec![1, 2, 3]);
+/// let (s2, v2) = (s1.clone(), v1.clone());
+/// let mut set = HashSet::new();
+/// set.insert(a(&s1, &v1, 0));
+/// set.insert(a(&s2, &v2, 1));
+/// assert_eq!(set.len(), 1);
+///
+/// assert!(tagged(&s1, 0) == tagged(&s2, 0));
+/// assert!(tagged(&s1, 0) != tagged(&s2, 1));
+#[cfg(feature = "derive")]
+pub use borrow_as_derive::ViewKey;
+
+/// Visitor used by `LifeRef::add_mut_checked` and `LifeRef::add_ref_checked`,
+/// which panics when visited element overlaps with the one being added while either of them is `Mut`.
+#[derive(Copy, Clone, Debug)]
+pub struct MutAliasCheck {
+    start: usize,
+    end: usize,
+    mutable: bool,
+}
+
+impl MutAliasCheck {
+    fn new<T: ?Sized>(r: &T, mutable: bool) -> Self {
+        let start = r as *const T as *const () as usize;
+        Self {
+            start,
+            end: start + core::mem::size_of_val(r),
+            mutable,
+        }
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    fn check<T: ?Sized>(&self, name: &'static str, other: &T) {
+        if self.overlaps(&Self::new(other, false)) {
+            let kind = if self.mutable { "mutable" } else { "immutable" };
+            panic!("{} reference aliases with element {}", kind, name);
+        }
+    }
+}
+
+impl<T: ?Sized> Visit<Mut<T>> for MutAliasCheck {
+    fn visit(&mut self, name: &'static str, field: &Mut<T>) {
+        self.check(name, unsafe { field.get() });
+    }
+}
+
+impl<T: ?Sized> Visit<Ref<T>> for MutAliasCheck {
+    fn visit(&mut self, name: &'static str, field: &Ref<T>) {
+        if self.mutable {
+            self.check(name, field.deref());
+        }
+    }
+}
+
+/// Visitor used by `LifeRef::finish`, which panics when any two visited elements overlap while either of them is `Mut`.
+///
+/// Checks up to 16 elements, matching the longest tuple `add_ref` and `add_mut` build, and panics when visiting more
+/// rather than skipping the rest.
+/// # Example
+/// ```should_panic
+/// use borrow_as::{AliasScan, LifeRef, Visit};
+///
+/// let x = 0;
+/// let r = LifeRef::wrap_ref(&x);
+/// let mut scan = AliasScan::default();
+/// for _ in 0..17 {
+///     scan.visit("x", &r.0);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AliasScan {
+    seen: [(MutAliasCheck, &'static str); 16],
+    len: usize,
+}
+
+impl Default for AliasScan {
+    fn default() -> Self {
+        Self {
+            seen: [(MutAliasCheck { start: 0, end: 0, mutable: false }, ""); 16],
+            len: 0,
+        }
+    }
+}
+
+impl AliasScan {
+    fn scan<T: ?Sized>(&mut self, name: &'static str, r: &T, mutable: bool) {
+        let cur = MutAliasCheck::new(r, mutable);
+        for (prev, prev_name) in &self.seen[..self.len] {
+            if (mutable || prev.mutable) && cur.overlaps(prev) {
+                let kind = if mutable { "mutable" } else { "immutable" };
+                panic!("{} reference at element {} aliases with element {}", kind, name, prev_name);
+            }
+        }
+        assert!(self.len < self.seen.len(), "alias check supports at most 16 elements, element {} is past the limit", name);
+        self.seen[self.len] = (cur, name);
+        self.len += 1;
+    }
+}
+
+impl<T: ?Sized> Visit<Mut<T>> for AliasScan {
+    fn visit(&mut self, name: &'static str, field: &Mut<T>) {
+        self.scan(name, unsafe { field.get() }, true);
+    }
+}
+
+impl<T: ?Sized> Visit<Ref<T>> for AliasScan {
+    fn visit(&mut self, name: &'static str, field: &Ref<T>) {
+        self.scan(name, field.deref(), false);
+    }
+}
 
 /// Container for value which remains valid over specified lifetime.
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+///
+/// `LifeRef` is `Send` and `Sync` whenever the inner value is.
+///
+/// Lifetime `'a` is covariant, so `LifeRef` can always be shortened. Variance of inner value follows its elements:
+/// `Ref<T>` is covariant in `T` as `&T` is, while `Mut<T>` is invariant in `T` as `&mut T` is.
+///
+/// `Default` requires inner value to be `Default`, which `Ref` and `Mut` never are, so default `LifeRef` can't hold dangling reference.
+///
+/// Inner value is held by value, so it must be `Sized`: unsized targets such as `str` or `dyn Trait` are kept
+/// behind `Ref` or `Mut` instead, e.g. `LifeRef<'a, Ref<str>>` or `LifeRef<'a, Ref<dyn Fn() -> i32>>`.
+/// There's nothing to gain from `LifeRef<'a, str>`, which would own the string rather than borrow it.
+///
+/// Every reference passed to `add_ref` and `add_mut` is borrowed for `'a`, so the borrow checker rejects adding the same place
+/// both immutably and mutably, while disjoint fields can be mixed freely. Overlap of references obtained through unsafe code
+/// is caught at runtime by `add_mut_checked` and `add_ref_checked` instead.
+/// # Example
+/// ```
+/// let x = 42;
+/// let r = borrow_as::LifeRef::wrap_ref(&x);
+/// std::thread::scope(|s| {
+///     s.spawn(move || assert_eq!(r.0, &42));
+/// });
+/// ```
+/// Comparing `LifeRef`s compares referenced values element by element, even if they have different lifetimes:
+/// ```
+/// use borrow_as::LifeRef;
+/// let (a, s) = (1, String::from("Referenced"));
+/// let r = LifeRef::wrap_ref(&a).add_ref(s.as_str());
+/// {
+///     let (b, t) = (1, String::from("Referenced"));
+///     let other = LifeRef::wrap_ref(&b).add_ref(t.as_str());
+///     assert_eq!(r, other);
+///     assert_ne!(r, LifeRef::wrap_ref(&b).add_ref(&t[..3]));
+/// }
+/// ```
+/// Unsized targets behind `Ref`:
+/// ```
+/// use borrow_as::{LifeRef, Ref};
+/// let s = String::from("Referenced");
+/// let f = || 42;
+/// let r: LifeRef<'_, Ref<str>> = LifeRef::wrap_ref(s.as_str()).map_life(|(s,)| s);
+/// let g: LifeRef<'_, Ref<dyn Fn() -> i32>> = LifeRef::wrap_ref(&f as &dyn Fn() -> i32).map_life(|(f,)| f);
+/// assert_eq!(r.as_target().len(), 10);
+/// assert_eq!(g.as_target()(), 42);
+/// ```
+/// Disjoint fields of the same struct can be borrowed immutably and mutably at once:
+/// ```
+/// struct S {
+///     a: i32,
+///     b: i32,
+/// }
+///
+/// let mut s = S { a: 1, b: 2 };
+/// let r = borrow_as::LifeRef::wrap_ref(&s.a).add_mut(&mut s.b);
+/// r.1.set(*r.0 + 10);
+/// assert_eq!(s.b, 11);
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
 #[repr(transparent)]
 pub struct LifeRef<'a, T>{
     inner: T,
-    phantom: PhantomData<&'a T>,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> LifeRef<'a, ()> {
+    /// Creates `LifeRef` with empty tuple for chaining with other methods.
+    /// # Example
+    /// ```
+    /// let (a, b) = (1, "two");
+    /// let r = borrow_as::LifeRef::empty().add_ref(&a).add_ref(b);
+    /// assert_eq!(r.0, &1);
+    /// assert_eq!(r.1, "two");
+    pub const fn empty() -> Self {
+        Self {
+            inner: (),
+            phantom: PhantomData,
+        }
+    }
 }
 
 impl<'a, T: ?Sized> LifeRef<'a, (Ref<T>,)> {
     /// Wraps immutable reference with inner value represented as 1-tuple for chaining with other methods.
     /// # Example
     /// ```
-    /// let s = String::from("Referenced");
-    /// let r = borrow_as::LifeRef::wrap_ref(&s[..3]);
-    /// assert_eq!(r.0, "Ref");
-    pub fn wrap_ref(r: &'a T) -> Self {
-        Self {
-            inner: (Ref(r),),
-            phantom: PhantomData,
+    /// let s = String::from("Referenced");
+    /// let r = borrow_as::LifeRef::wrap_ref(&s[..3]);
+    /// assert_eq!(r.0, "Ref");
+    /// ```
+    /// Can be used in const context, same as `wrap_mut`, `wrap_cell` and `empty`:
+    /// ```
+    /// use borrow_as::{LifeRef, Ref};
+    /// static X: i32 = 42;
+    /// const R: LifeRef<'static, (Ref<i32>,)> = LifeRef::wrap_ref(&X);
+    /// assert_eq!(R.0, &42);
+    pub const fn wrap_ref(r: &'a T) -> Self {
+        Self {
+            inner: (Ref::new(r),),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns referenced value of the single element.
+    /// # Example
+    /// ```
+    /// let x = 42;
+    /// let r = borrow_as::LifeRef::wrap_ref(&x);
+    /// assert_eq!(r.as_target(), &42);
+    pub fn as_target(&self) -> &T {
+        &self.inner.0
+    }
+}
+
+impl<'a, T: ?Sized> LifeRef<'a, Ref<T>> {
+    /// Returns referenced value.
+    /// # Example
+    /// ```
+    /// let x = 42;
+    /// let r = borrow_as::LifeRef::wrap_ref(&x).map_life(|(x,)| x);
+    /// assert_eq!(r.as_target(), &42);
+    pub fn as_target(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<'a, T> LifeRef<'a, Ref<[T]>> {
+    /// Wraps immutable slice, e.g. an arena of preallocated values, as single `Ref`.
+    /// # Example
+    /// ```
+    /// use borrow_as::{LifeRef, Ref};
+    /// static ARENA: [u32; 4] = [1, 2, 3, 4];
+    /// const R: LifeRef<'static, Ref<[u32]>> = LifeRef::wrap_slice_of(&ARENA);
+    /// assert_eq!(R.iter_refs().map(|x| **x).sum::<u32>(), 10);
+    /// assert_eq!(*R.slice(1..3), [2, 3]);
+    pub const fn wrap_slice_of(arena: &'a [T]) -> Self {
+        Self {
+            inner: Ref::new(arena),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> LifeRef<'a, Mut<[T]>> {
+    /// Wraps mutable slice, e.g. an arena of preallocated values, as single `Mut`.
+    /// # Example
+    /// ```
+    /// let mut arena = [0u32; 4];
+    /// let r = borrow_as::LifeRef::wrap_slice_mut(&mut arena);
+    /// for (i, x) in r.iter_muts().enumerate() {
+    ///     x.set(i as u32);
+    /// }
+    /// r.slice(2..).fill(9);
+    /// assert_eq!(arena, [0, 1, 9, 9]);
+    pub const fn wrap_slice_mut(arena: &'a mut [T]) -> Self {
+        Self {
+            inner: Mut::from_mut(arena),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized> LifeRef<'a, (Mut<T>,)> {
+    /// Wraps mutable reference with inner value represented as 1-tuple for chaining with other methods.
+    /// # Example
+    /// ```
+    /// let mut v = vec![1, 2, 3];
+    /// let r_mut = borrow_as::LifeRef::wrap_mut(v.as_mut_slice());
+    /// let v_mut = r_mut.0.as_slice_of_cells();
+    /// v_mut[2].set(4);
+    /// assert_eq!(v, [1, 2, 4]);
+    pub const fn wrap_mut(r: &'a mut T) -> Self {
+        Self {
+            inner: (Mut::from_mut(r),),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Wraps shared reference to cell with inner value represented as 1-tuple for chaining with other methods.
+    /// # Example
+    /// ```
+    /// use std::cell::Cell;
+    /// let c = Cell::new(1);
+    /// let r = borrow_as::LifeRef::wrap_cell(&c);
+    /// r.0.set(2);
+    /// c.set(c.get() + 1);
+    /// assert_eq!(r.0.get(), 3);
+    pub const fn wrap_cell(c: &'a Cell<T>) -> Self {
+        Self {
+            inner: (Mut::from_cell(c),),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized, const N: usize> LifeRef<'a, [Ref<T>; N]> {
+    /// Wraps array of immutable references into array of `Ref`s sharing single lifetime.
+    /// # Example
+    /// ```
+    /// let (a, b, c) = (1, 2, 3);
+    /// let r = borrow_as::LifeRef::wrap_ref_array([&a, &b, &c]);
+    /// assert_eq!(r[1], &2);
+    /// let doubled = r.map_life(|arr| arr.map(|x| *x * 2));
+    /// assert_eq!(*doubled, [2, 4, 6]);
+    pub fn wrap_ref_array(arr: [&'a T; N]) -> Self {
+        Self {
+            inner: arr.map(|r| Ref::new(r)),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns iterator over wrapped references.
+    /// # Example
+    /// ```
+    /// let (a, b) = (1, 2);
+    /// let r = borrow_as::LifeRef::wrap_ref_array([&a, &b]);
+    /// assert_eq!(r.iter().map(|x| **x).sum::<i32>(), 3);
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Ref<T>> + ExactSizeIterator + '_ {
+        self.inner.iter()
+    }
+}
+
+impl<'a, A, B> LifeRef<'a, (A, B)> {
+    /// Splits combined view into two views sharing the same lifetime.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// struct A {
+    ///     s: Ref<str>,
+    /// }
+    ///
+    /// struct B {
+    ///     n: Mut<u32>,
+    /// }
+    ///
+    /// fn len(a: LifeRef<'_, A>) -> usize {
+    ///     a.s.len()
+    /// }
+    ///
+    /// fn set(b: LifeRef<'_, B>, n: usize) {
+    ///     b.n.set(n as u32);
+    /// }
+    ///
+    /// let s = String::from("Referenced");
+    /// let mut n = 0;
+    /// let a = LifeRef::wrap_ref(s.as_str()).map_life(|(s,)| A { s });
+    /// let b = LifeRef::wrap_mut(&mut n).map_life(|(n,)| B { n });
+    /// let (a, b) = a.wrap_life().add_life(b).unzip();
+    /// set(b, len(a));
+    /// assert_eq!(n, 10);
+    pub fn unzip(self) -> (LifeRef<'a, A>, LifeRef<'a, B>) {
+        let (a, b) = self.inner;
+        let a = LifeRef {
+            inner: a,
+            phantom: PhantomData,
+        };
+        let b = LifeRef {
+            inner: b,
+            phantom: PhantomData,
+        };
+        (a, b)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: ?Sized> LifeRef<'a, alloc::vec::Vec<Ref<T>>> {
+    /// Wraps vector of immutable references into vector of `Ref`s sharing single lifetime.
+    /// # Example
+    /// ```
+    /// let v = vec![1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_ref_vec(v.iter().collect());
+    /// assert_eq!(r.iter().map(|x| **x).sum::<i32>(), 6);
+    /// assert_eq!(r[2], &3);
+    pub fn wrap_ref_vec(v: alloc::vec::Vec<&'a T>) -> Self {
+        Self {
+            inner: v.into_iter().map(|r| Ref::new(r)).collect(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns iterator over wrapped references.
+    /// # Example
+    /// ```
+    /// let v = vec![1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_ref_vec(v.iter().collect());
+    /// assert_eq!(r.iter().map(|x| **x).sum::<i32>(), 6);
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Ref<T>> + ExactSizeIterator + '_ {
+        self.inner.iter()
+    }
+}
+
+impl<'a, T> LifeRef<'a, T> {
+    /// Creates `LifeRef` from value produced by `f` out of borrowed `owner`, tying it to the borrow.
+    ///
+    /// Bare `Ref` and `Mut` can only be obtained through unsafe `LifeRef::into_inner`, so there's no safe round-trip:
+    /// `f` is meant for handles already kept by self-referential helpers, re-binding them to `'a`.
+    /// Views built in safe code don't need it, as `add_ref` and `add_mut` tie their handles to `'a` already.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// let v = vec![3, 1, 4];
+    /// let r = LifeRef::from_owned(&v, |v| {
+    ///     let (head, tail) = v.split_first().unwrap();
+    ///     // SAFETY: handles point into `v` and are re-bound to its borrow by `from_owned` right away.
+    ///     unsafe { LifeRef::wrap_ref(head).add_ref(tail).into_inner() }
+    /// });
+    /// assert_eq!(r.0, &3);
+    /// assert_eq!(r.1, [1, 4]);
+    pub fn from_owned<O: ?Sized>(owner: &'a O, f: impl FnOnce(&'a O) -> T) -> Self {
+        Self {
+            inner: f(owner),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Wraps inner value into 1-tuple for chaining with other methods.
+    /// # Example
+    /// ```
+    /// let u = borrow_as::LifeRef::from(true);
+    /// assert!(*u);
+    /// let uu = u.wrap_life();
+    /// assert!(uu.0);
+    pub fn wrap_life(self) -> LifeRef<'a, (T,)> {
+        LifeRef {
+            inner: (self.inner,),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by one element which represents passed immutable reference. Supports extending up to 16 elements.
+    /// # Example
+    /// ```
+    /// let t = (0, 1);
+    /// let s = String::from("Referenced");
+    /// let r = borrow_as::LifeRef::wrap_ref(&t).add_ref(&s[..3]).add_ref(&42);
+    /// assert_eq!(r.0, &t);
+    /// assert_eq!(r.1, "Ref");
+    /// assert_eq!(r.2, &42);
+    pub fn add_ref<U>(self, r: &'a U) -> LifeRef<'a, T::Output> where
+    T: Append<Ref<U>>,
+    U: 'a + ?Sized {
+        let t = self.inner;
+        let v = t.append(Ref::new(r));
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by `N` elements which represent passed immutable references in order.
+    /// Supports extending up to 16 elements.
+    /// # Example
+    /// ```
+    /// let x = 0;
+    /// let arr = [1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_ref(&x).add_array_ref([&arr[0], &arr[1], &arr[2]]);
+    /// assert_eq!(r.0, &0);
+    /// assert_eq!(r.1, &1);
+    /// assert_eq!(r.2, &2);
+    /// assert_eq!(r.3, &3);
+    pub fn add_array_ref<U, const N: usize>(self, arr: [&'a U; N]) -> LifeRef<'a, T::Output> where
+    T: tuple::AppendArray<Ref<U>, N>,
+    U: 'a + ?Sized {
+        LifeRef {
+            inner: self.inner.append_array(arr.map(|r| Ref::new(r))),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by `N` copies of passed immutable reference. Supports extending up to 16 elements.
+    ///
+    /// There's no mutable counterpart, as several `Mut` handles to the same value would alias.
+    /// # Example
+    /// ```
+    /// let (a, b) = (0, 1);
+    /// let r = borrow_as::LifeRef::wrap_ref(&a).add_ref_n::<_, 2>(&b);
+    /// assert_eq!(r.1, &1);
+    /// assert_eq!(r.1, r.2);
+    /// assert!(r.1.ptr_eq(&r.2));
+    pub fn add_ref_n<U, const N: usize>(self, r: &'a U) -> LifeRef<'a, T::Output> where
+    T: tuple::AppendArray<Ref<U>, N>,
+    U: 'a + ?Sized {
+        LifeRef {
+            inner: self.inner.append_array(core::array::from_fn(|_| Ref::new(r))),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by one element which represents immutable reference returned by `f` from current inner tuple.
+    /// Supports extending up to 16 elements.
+    ///
+    /// Note: returned reference must be valid for `'a`, so it can't borrow from the tuple itself,
+    /// only from data captured by `f` which outlives `'a`.
+    /// # Example
+    /// ```
+    /// let v = vec![3, 1, 4, 1, 5];
+    /// let r = borrow_as::LifeRef::wrap_ref(&v).add_ref_map(|(all,)| &v[..all.len() / 2]);
+    /// assert_eq!(r.0.len(), 5);
+    /// assert_eq!(r.1, [3, 1]);
+    pub fn add_ref_map<U>(self, f: impl FnOnce(&T) -> &'a U) -> LifeRef<'a, T::Output> where
+    T: Append<Ref<U>>,
+    U: 'a + ?Sized {
+        let r = f(&self.inner);
+        self.add_ref(r)
+    }
+
+    /// Extends inner tuple by existing immutable reference, e.g. produced by `Ref::slice` or `Ref::at`.
+    /// Supports extending up to 16 elements.
+    ///
+    /// # Safety
+    /// `Ref` doesn't carry lifetime, so the compiler can't check it: value referenced by `r` must stay valid
+    /// and must not be written for the whole `'a`, e.g. by coming from a `LifeRef` living at least as long as `'a`.
+    /// # Example
+    /// ```
+    /// let v = vec![1u8, 2, 3, 4];
+    /// let n = 0;
+    /// let whole = borrow_as::LifeRef::wrap_ref(v.as_slice());
+    /// // SAFETY: `whole` outlives `r`.
+    /// let r = unsafe { borrow_as::LifeRef::wrap_ref(&n).add_ref_wrapped(whole.0.slice(1..3).into_inner()) };
+    /// assert_eq!(r.1, [2, 3]);
+    pub unsafe fn add_ref_wrapped<U>(self, r: Ref<U>) -> LifeRef<'a, T::Output> where
+    T: Append<Ref<U>>,
+    U: 'a + ?Sized {
+        LifeRef {
+            inner: self.inner.append(r),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by existing mutable reference, e.g. produced by `Mut::slice` or `Mut::iter_muts`.
+    /// Supports extending up to 16 elements.
+    ///
+    /// # Safety
+    /// `Mut` doesn't carry lifetime, so the compiler can't check it: value referenced by `r` must stay valid
+    /// and must not be accessed other than through `Mut` handles for the whole `'a`, e.g. by coming from a `LifeRef`
+    /// living at least as long as `'a`.
+    /// # Example
+    /// ```
+    /// let mut v = vec![1u8, 2, 3, 4];
+    /// let whole = borrow_as::LifeRef::wrap_mut(v.as_mut_slice());
+    /// // SAFETY: `whole` outlives `r`.
+    /// let r = unsafe { borrow_as::LifeRef::empty().add_mut_wrapped(whole.0.slice(2..).into_inner()) };
+    /// r.0.fill(0);
+    /// assert_eq!(v, [1, 2, 0, 0]);
+    pub unsafe fn add_mut_wrapped<U>(self, r: Mut<U>) -> LifeRef<'a, T::Output> where
+    T: Append<Mut<U>>,
+    U: 'a + ?Sized {
+        LifeRef {
+            inner: self.inner.append(r),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by one element which represents passed immutable reference converted with `AsRef<B>`.
+    /// Supports extending up to 16 elements.
+    /// # Example
+    /// ```
+    /// let s = String::from("Referenced");
+    /// let v = vec![1u8, 2, 3];
+    /// let r = borrow_as::LifeRef::empty().add_ref_as::<str>(&s).add_ref_as::<[u8]>(&v);
+    /// assert_eq!(r.0, "Referenced");
+    /// assert_eq!(r.1, [1, 2, 3]);
+    pub fn add_ref_as<B>(self, r: &'a (impl AsRef<B> + ?Sized)) -> LifeRef<'a, T::Output> where
+    T: Append<Ref<B>>,
+    B: 'a + ?Sized {
+        self.add_ref(r.as_ref())
+    }
+
+    /// Extends inner tuple by one element which represents passed immutable reference converted with `Borrow<B>`.
+    /// Supports extending up to 16 elements.
+    /// # Example
+    /// ```
+    /// let s = String::from("Referenced");
+    /// let v = vec![1u8, 2, 3];
+    /// let r = borrow_as::LifeRef::empty().add_ref_borrow::<str>(&s).add_ref_borrow::<[u8]>(&v);
+    /// assert_eq!(r.0, "Referenced");
+    /// assert_eq!(r.1, [1, 2, 3]);
+    pub fn add_ref_borrow<B>(self, r: &'a (impl Borrow<B> + ?Sized)) -> LifeRef<'a, T::Output> where
+    T: Append<Ref<B>>,
+    B: 'a + ?Sized {
+        self.add_ref(r.borrow())
+    }
+
+    /// Extends inner tuple by one element which represents passed mutable reference. Supports extending up to 16 elements.
+    ///
+    /// Overlaps with elements already in the tuple aren't checked, as safe code can't produce them.
+    /// Use `add_mut_checked` or `finish` to check references obtained through unsafe code.
+    /// # Example
+    /// ```
+    /// let mut t = (0, 1);
+    /// let mut s = String::from("Unaltered");
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut t).add_mut(&mut s);
+    ///
+    /// let (y, x) = r.0.get();
+    /// r.0.set((x, y));
+    ///
+    /// let mut r1 = r.1.take();
+    /// r1.replace_range(..3, "A");
+    /// r.1.set(r1);
+    ///
+    /// assert_eq!(t, (1, 0));
+    /// assert_eq!(s, "Altered");
+    pub fn add_mut<U>(self, r: &'a mut U) -> LifeRef<'a, T::Output> where
+    T: Append<Mut<U>>,
+    U: 'a + ?Sized {
+        let t = self.inner;
+        let v = t.append(Mut::from_mut(r));
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by one element which represents passed pinned mutable reference. Supports extending up to 16 elements.
+    ///
+    /// See `PinMut` for example.
+    pub fn add_pin_mut<U>(self, r: Pin<&'a mut U>) -> LifeRef<'a, T::Output> where
+    T: Append<PinMut<U>>,
+    U: 'a + ?Sized {
+        let r = unsafe { Pin::into_inner_unchecked(r) };
+        LifeRef {
+            inner: self.inner.append(PinMut(r)),
+            phantom: PhantomData,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn __add_ref_named<U>(self, r: &'a U, name: &'static str) -> LifeRef<'a, T::Output> where
+    T: Append<Ref<U>>,
+    U: 'a + ?Sized {
+        LifeRef {
+            inner: self.inner.append(Ref::named(r, name)),
+            phantom: PhantomData,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn __add_mut_named<U>(self, r: &'a mut U, name: &'static str) -> LifeRef<'a, T::Output> where
+    T: Append<Mut<U>>,
+    U: 'a + ?Sized {
+        LifeRef {
+            inner: self.inner.append(Mut::named(r, name)),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by one element which represents passed shared reference to cell. Supports extending up to 16 elements.
+    /// # Example
+    /// ```
+    /// use std::cell::Cell;
+    /// let a = 1;
+    /// let c = Cell::new(0);
+    /// let r = borrow_as::LifeRef::wrap_ref(&a).add_cell(&c).add_cell(&c);
+    /// r.1.set(*r.0);
+    /// r.2.set(r.2.get() + 1);
+    /// assert_eq!(c.get(), 2);
+    pub fn add_cell<U>(self, c: &'a Cell<U>) -> LifeRef<'a, T::Output> where
+    T: Append<Mut<U>>,
+    U: 'a + ?Sized {
+        // SAFETY: `c` is borrowed for `'a` and `Cell` allows shared writes.
+        unsafe { self.add_mut_wrapped(Mut::from_cell(c)) }
+    }
+
+    /// Extends inner tuple by one element which represents passed mutable reference. Supports extending up to 16 elements.
+    ///
+    /// In debug builds or with `runtime-check` feature panics if passed reference overlaps with any element already in the tuple,
+    /// which can only happen if it was obtained through unsafe code. Otherwise it's the same as `add_mut`.
+    /// Panicking examples are only run with `runtime-check` feature, as release builds skip the check without it.
+    /// # Example
+    /// ```
+    /// let mut a = [1, 2];
+    /// let mut b = 3;
+    /// let (a0, a1) = a.split_at_mut(1);
+    /// let r = borrow_as::LifeRef::wrap_mut(a0).add_mut_checked(a1).add_mut_checked(&mut b);
+    /// r.2.set(4);
+    /// assert_eq!(b, 4);
+    /// ```
+    /// Contents of a cell already in the tuple borrowed mutably:
+    #[cfg_attr(feature = "runtime-check", doc = "```should_panic")]
+    #[cfg_attr(not(feature = "runtime-check"), doc = "```no_run")]
+    /// let c = std::cell::Cell::new(0);
+    /// let r = borrow_as::LifeRef::empty().add_cell(&c).add_mut_checked(unsafe { &mut *c.as_ptr() });
+    /// ```
+    /// Cell borrowed immutably, then its contents mutably:
+    #[cfg_attr(feature = "runtime-check", doc = "```should_panic")]
+    #[cfg_attr(not(feature = "runtime-check"), doc = "```no_run")]
+    /// let c = std::cell::Cell::new((0, 1));
+    /// let r = borrow_as::LifeRef::wrap_ref(&c).add_mut_checked(unsafe { &mut *c.as_ptr() });
+    pub fn add_mut_checked<U>(self, r: &'a mut U) -> LifeRef<'a, T::Output> where
+    T: Append<Mut<U>> + BorrowFields<MutAliasCheck>,
+    U: 'a + ?Sized {
+        #[cfg(any(debug_assertions, feature = "runtime-check"))]
+        self.inner.visit_fields(&mut MutAliasCheck::new(r, true));
+        self.add_mut(r)
+    }
+
+    /// Extends inner tuple by one element which represents passed immutable reference. Supports extending up to 16 elements.
+    ///
+    /// In debug builds or with `runtime-check` feature panics if passed reference overlaps with any `Mut` already in the tuple,
+    /// which can only happen if it was obtained through unsafe code. Otherwise it's the same as `add_ref`.
+    /// Panicking examples are only run with `runtime-check` feature, as release builds skip the check without it.
+    /// # Example
+    /// ```
+    /// let mut a = 1;
+    /// let b = 2;
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut a).add_ref_checked(&b).add_ref_checked(&b);
+    /// r.0.set(*r.1 + *r.2);
+    /// assert_eq!(a, 4);
+    /// ```
+    #[cfg_attr(feature = "runtime-check", doc = "```should_panic")]
+    #[cfg_attr(not(feature = "runtime-check"), doc = "```no_run")]
+    /// let c = std::cell::Cell::new([0, 1]);
+    /// let r = borrow_as::LifeRef::empty().add_cell(&c).add_ref_checked(unsafe { &(*c.as_ptr())[1] });
+    pub fn add_ref_checked<U>(self, r: &'a U) -> LifeRef<'a, T::Output> where
+    T: Append<Ref<U>> + BorrowFields<MutAliasCheck>,
+    U: 'a + ?Sized {
+        #[cfg(any(debug_assertions, feature = "runtime-check"))]
+        self.inner.visit_fields(&mut MutAliasCheck::new(r, false));
+        self.add_ref(r)
+    }
+
+    /// Marks the end of building, returning the same view.
+    ///
+    /// In debug builds or with `runtime-check` feature panics if any two elements overlap while either of them is `Mut`,
+    /// which can only happen if they were obtained through unsafe code. Inner tuple must consist of `Ref` and `Mut` elements.
+    /// Views with more than 16 `Ref` and `Mut` fields panic as well, since `AliasScan` checks at most that many.
+    /// Panicking examples are only run with `runtime-check` feature, as release builds skip the check without it.
+    /// # Example
+    /// ```
+    /// let (mut a, b) = (1, 2);
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut a).add_ref(&b).add_ref(&b).finish();
+    /// r.0.set(*r.1 + *r.2);
+    /// assert_eq!(a, 4);
+    /// ```
+    /// Contents of a cell already in the tuple borrowed mutably:
+    #[cfg_attr(feature = "runtime-check", doc = "```should_panic")]
+    #[cfg_attr(not(feature = "runtime-check"), doc = "```no_run")]
+    /// let c = std::cell::Cell::new(0);
+    /// let r = borrow_as::LifeRef::empty().add_cell(&c).add_mut(unsafe { &mut *c.as_ptr() }).finish();
+    pub fn finish(self) -> Self where
+    T: BorrowFields<AliasScan> {
+        #[cfg(any(debug_assertions, feature = "runtime-check"))]
+        self.inner.visit_fields(&mut AliasScan::default());
+        self
+    }
+
+    /// Extends inner tuple by one element which represents passed optional immutable reference. Supports extending up to 16 elements.
+    /// # Example
+    /// ```
+    /// let (a, b) = (1, 2);
+    /// let r = borrow_as::LifeRef::wrap_ref(&a).add_ref_opt(Some(&b)).add_ref_opt(None::<&i32>);
+    /// assert_eq!(r.1.as_deref(), Some(&2));
+    /// assert!(r.2.is_none());
+    pub fn add_ref_opt<U>(self, r: Option<&'a U>) -> LifeRef<'a, T::Output> where
+    T: Append<Option<Ref<U>>>,
+    U: 'a + ?Sized {
+        let t = self.inner;
+        let v = t.append(r.map(|r| Ref::new(r)));
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by one element which represents passed optional mutable reference. Supports extending up to 16 elements.
+    /// # Example
+    /// ```
+    /// let a = 1;
+    /// let mut b = 2;
+    /// let r = borrow_as::LifeRef::wrap_ref(&a).add_mut_opt(Some(&mut b)).add_mut_opt(None::<&mut i32>);
+    /// if let Some(b) = &r.1 {
+    ///     b.set(3);
+    /// }
+    /// assert!(r.2.is_none());
+    /// assert_eq!(b, 3);
+    pub fn add_mut_opt<U>(self, r: Option<&'a mut U>) -> LifeRef<'a, T::Output> where
+    T: Append<Option<Mut<U>>>,
+    U: 'a + ?Sized {
+        let t = self.inner;
+        let v = t.append(r.map(|r| Mut::from_mut(r)));
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by one element which represents passed immutable reference.
+    /// Once inner tuple has 16 elements, it's nested into the first element of a new tuple, so extending can continue past 16.
+    ///
+    /// Note: nesting can be done manually at any point with `wrap_life`.
+    /// # Example
+    /// ```
+    /// let v: Vec<u32> = (0..18).collect();
+    /// let r = borrow_as::LifeRef::wrap_ref(&v[0])
+    ///     .add_ref_grouped(&v[1]).add_ref_grouped(&v[2]).add_ref_grouped(&v[3])
+    ///     .add_ref_grouped(&v[4]).add_ref_grouped(&v[5]).add_ref_grouped(&v[6])
+    ///     .add_ref_grouped(&v[7]).add_ref_grouped(&v[8]).add_ref_grouped(&v[9])
+    ///     .add_ref_grouped(&v[10]).add_ref_grouped(&v[11]).add_ref_grouped(&v[12])
+    ///     .add_ref_grouped(&v[13]).add_ref_grouped(&v[14]).add_ref_grouped(&v[15])
+    ///     .add_ref_grouped(&v[16]).add_ref_grouped(&v[17]);
+    /// assert_eq!(r.0.0, &0);
+    /// assert_eq!(r.0.15, &15);
+    /// assert_eq!(r.1, &16);
+    /// assert_eq!(r.2, &17);
+    pub fn add_ref_grouped<U>(self, r: &'a U) -> LifeRef<'a, T::Output> where
+    T: tuple::AppendGrouped<Ref<U>>,
+    U: 'a + ?Sized {
+        let t = self.inner;
+        let v = t.append_grouped(Ref::new(r));
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple by one element which represents passed mutable reference.
+    /// Once inner tuple has 16 elements, it's nested into the first element of a new tuple, so extending can continue past 16.
+    ///
+    /// Note: nesting can be done manually at any point with `wrap_life`.
+    /// # Example
+    /// ```
+    /// let mut x = 0;
+    /// let full = borrow_as::LifeRef::from((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+    /// let r = full.add_mut_grouped(&mut x);
+    /// r.1.set(r.0.15);
+    /// assert_eq!(x, 15);
+    pub fn add_mut_grouped<U>(self, r: &'a mut U) -> LifeRef<'a, T::Output> where
+    T: tuple::AppendGrouped<Mut<U>>,
+    U: 'a + ?Sized {
+        let t = self.inner;
+        let v = t.append_grouped(Mut::from_mut(r));
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple with extracted value from another `LifeRef`.
+    ///
+    /// Note: `other` can't outlive `self` and its lifetime will be shortened accordingly.
+    /// # Example
+    /// ```
+    /// use borrow_as::LifeRef as Life;
+    /// struct A;
+    /// struct B;
+    /// struct C;
+    /// let a = Life::from(A).wrap_life();
+    /// let b = Life::from(B);
+    /// let c = Life::from(C);
+    /// let ab = a.add_life(b).wrap_life();
+    /// let abc: Life<'_, ((A, B), C)> = ab.add_life(c);
+    pub fn add_life<'b, U>(self, other: LifeRef<'b, U>) -> LifeRef<'a, T::Output> where
+    T: Append<U>,
+    'b: 'a {
+        let t = self.inner;
+        let v = t.append(other.inner);
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+    
+    /// Extends inner tuple with extracted value from another `LifeRef`.
+    ///
+    /// Note: `self` can't outlive `other` and its lifetime will be shortened accordingly.
+    /// # Example
+    /// ```
+    /// use borrow_as::LifeRef as Life;
+    /// fn join<'long: 'short, 'short>(a: Life<'long, (i32,)>, b: Life<'short, i32>) -> Life<'short, (i32, i32)> {
+    ///     a.add_life_longer(b)
+    /// }
+    ///
+    /// let ab = join(Life::from(1).wrap_life(), Life::from(2));
+    /// assert_eq!(*ab, (1, 2));
+    pub fn add_life_longer<'b, U>(self, other: LifeRef<'b, U>) -> LifeRef<'b, T::Output> where
+    T: Append<U>,
+    'a: 'b {
+        let t = self.inner;
+        let v = t.append(other.inner);
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple at the front by one element which represents passed immutable reference. Supports extending up to 16 elements.
+    /// # Example
+    /// ```
+    /// let (a, b, c) = (1, 2, 3);
+    /// let r = borrow_as::LifeRef::wrap_ref(&c).prepend_ref(&b).prepend_ref(&a);
+    /// assert_eq!(r.0, &1);
+    /// assert_eq!(r.1, &2);
+    /// assert_eq!(r.2, &3);
+    pub fn prepend_ref<U>(self, r: &'a U) -> LifeRef<'a, T::Output> where
+    T: Prepend<Ref<U>>,
+    U: 'a + ?Sized {
+        let t = self.inner;
+        let v = t.prepend(Ref::new(r));
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple at the front by one element which represents passed mutable reference. Supports extending up to 16 elements.
+    /// # Example
+    /// ```
+    /// let a = 1;
+    /// let mut b = 2;
+    /// let r = borrow_as::LifeRef::wrap_ref(&a).prepend_mut(&mut b);
+    /// r.0.set(*r.1);
+    /// assert_eq!(b, 1);
+    pub fn prepend_mut<U>(self, r: &'a mut U) -> LifeRef<'a, T::Output> where
+    T: Prepend<Mut<U>>,
+    U: 'a + ?Sized {
+        let t = self.inner;
+        let v = t.prepend(Mut::from_mut(r));
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extends inner tuple at the front with extracted value from another `LifeRef`.
+    ///
+    /// Note: `other` can't outlive `self` and its lifetime will be shortened accordingly.
+    /// # Example
+    /// ```
+    /// use borrow_as::LifeRef as Life;
+    /// struct A;
+    /// struct B;
+    /// struct C;
+    /// let c = Life::from(C).wrap_life();
+    /// let b = Life::from(B);
+    /// let a = Life::from(A);
+    /// let abc: Life<'_, (A, B, C)> = c.prepend_life(b).prepend_life(a);
+    pub fn prepend_life<'b, U>(self, other: LifeRef<'b, U>) -> LifeRef<'a, T::Output> where
+    T: Prepend<U>,
+    'b: 'a {
+        let t = self.inner;
+        let v = t.prepend(other.inner);
+        LifeRef {
+            inner: v,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Converts wrapped value from one type to another.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// struct Test {
+    ///     x: Mut<String>,
+    ///     y: Ref<i32>,
+    ///     z: Mut<bool>,
+    /// }
+    /// 
+    /// let int = 42;
+    /// let mut string = String::from("Unaltered");
+    /// let mut flag = false;
+    /// let test = LifeRef::
+    ///     wrap_mut(&mut string)
+    ///     .add_ref(&int)
+    ///     .add_mut(&mut flag)
+    ///     .map_life(|(x, y, z)| Test { x, y, z });
+    /// 
+    /// let mut x = test.x.take();
+    /// x.replace_range(..3, "A");
+    /// test.x.set(x);
+    /// assert_eq!(test.y, &42);
+    /// test.z.set(!test.z.get());
+    /// 
+    /// assert_eq!(string, "Altered");
+    /// assert!(flag);
+    pub fn map_life<U>(self, f: impl Fn(T) -> U) -> LifeRef<'a, U> {
+        LifeRef {
+            inner: f(self.inner),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Converts inner tuple into struct `U`, matching tuple elements to its fields with `FromLifeTuple`.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// struct Port {
+    ///     host: Ref<str>,
+    ///     port: Ref<u16>,
+    /// }
+    ///
+    /// impl FromLifeTuple for Port {
+    ///     type Tuple = (Ref<str>, Ref<u16>);
+    ///
+    ///     fn from_life_tuple((host, port): Self::Tuple) -> Self {
+    ///         Self { host, port }
+    ///     }
+    /// }
+    ///
+    /// let host = String::from("localhost");
+    /// let port = 8080;
+    /// let p = LifeRef::wrap_ref(host.as_str()).add_ref(&port).build::<Port>();
+    /// assert_eq!(p.host, "localhost");
+    /// assert_eq!(p.port, &8080);
+    pub fn build<U>(self) -> LifeRef<'a, U> where
+    U: FromLifeTuple<Tuple = T> {
+        self.map_life(U::from_life_tuple)
+    }
+
+    /// Copies `LifeRef` under a shorter lifetime bound to the borrow of `self`, keeping the original usable afterwards.
+    /// # Example
+    /// ```
+    /// use borrow_as::LifeRef;
+    /// fn sum(r: LifeRef<'_, (&i32, &i32)>) -> i32 {
+    ///     r.0 + r.1
+    /// }
+    ///
+    /// let (a, b) = (1, 2);
+    /// let r = LifeRef::from((&a, &b));
+    /// {
+    ///     let short = r.reborrow();
+    ///     assert_eq!(sum(short), 3);
+    /// }
+    /// assert_eq!(sum(r), 3);
+    pub fn reborrow<'b>(&'b self) -> LifeRef<'b, T> where
+    T: Copy {
+        LifeRef {
+            inner: self.inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Narrows view to immutable reference returned by `f` from inner value, under a lifetime bound to the borrow of `self`.
+    ///
+    /// Unlike `map_life`, `f` can return reference derived from the tuple, which is why `self` is only borrowed.
+    /// # Example
+    /// ```
+    /// use borrow_as::{LifeRef, Ref};
+    /// let v = vec![1, 2, 3];
+    /// let r = LifeRef::wrap_ref(&v);
+    /// let p: LifeRef<'_, Ref<[i32]>> = r.project(|(v,)| &v[1..]);
+    /// assert_eq!(p.as_target(), &[2, 3]);
+    pub fn project<'b, U>(&'b self, f: impl FnOnce(&'b T) -> &'b U) -> LifeRef<'b, Ref<U>> where
+    U: 'b + ?Sized {
+        LifeRef {
+            inner: Ref::new(f(&self.inner)),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Narrows view to mutable reference to cell returned by `f` from inner value, under a lifetime bound to the borrow of `self`.
+    /// # Example
+    /// ```
+    /// let mut v = vec![1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(v.as_mut_slice());
+    /// let p = r.project_mut(|(v,)| &v.as_slice_of_cells()[2]);
+    /// p.set(4);
+    /// assert_eq!(v, [1, 2, 4]);
+    pub fn project_mut<'b, U>(&'b self, f: impl FnOnce(&'b T) -> &'b Cell<U>) -> LifeRef<'b, Mut<U>> where
+    U: 'b + ?Sized {
+        LifeRef {
+            inner: Mut::from_cell(f(&self.inner)),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Changes lifetime `'a` to arbitrary `'b`, which can be longer than the one borrow checker inferred.
+    ///
+    /// # Safety
+    /// Every value referenced by inner tuple must stay valid and must not be accessed in violation of its `Ref` or `Mut`
+    /// element for the whole `'b`. Prefer this to `core::mem::transmute`, which can also change the inner type by mistake.
+    /// # Example
+    /// ```
+    /// use borrow_as::{LifeRef, Ref};
+    /// let s: &String = Box::leak(Box::new(String::from("Leaked")));
+    /// let r = LifeRef::wrap_ref(s.as_str());
+    /// // SAFETY: leaked box is never freed nor mutated.
+    /// let r: LifeRef<'static, (Ref<str>,)> = unsafe { r.transmute_lifetime() };
+    /// assert_eq!(r.0, "Leaked");
+    pub unsafe fn transmute_lifetime<'b>(self) -> LifeRef<'b, T> {
+        LifeRef {
+            inner: self.inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Copies inner value into owned values, escaping lifetime `'a`.
+    /// # Example
+    /// ```
+    /// let owned = {
+    ///     let (a, mut b) = (1u8, 'b');
+    ///     let r = borrow_as::LifeRef::wrap_ref(&a).add_mut(&mut b);
+    ///     r.1.set('c');
+    ///     r.copied()
+    /// };
+    /// assert_eq!(owned, (1, 'c'));
+    pub fn copied(&self) -> T::Owned where
+    T: CopyOut {
+        self.inner.copy_out()
+    }
+
+    /// Converts inner tuple into std references borrowing `self`: `&T` for `Ref<T>` and `&Cell<T>` for `Mut<T>`.
+    /// # Example
+    /// ```
+    /// use std::cell::Cell;
+    /// let s = String::from("Referenced");
+    /// let mut i = 0i8;
+    /// let r = borrow_as::LifeRef::wrap_ref(s.as_str()).add_mut(&mut i);
+    /// let (s1, i1): (&str, &Cell<i8>) = r.as_std_refs();
+    /// i1.set(s1.len() as i8);
+    /// assert_eq!(i, 10);
+    pub fn as_std_refs<'r>(&'r self) -> T::Output where
+    T: AsStdRefs<'r> {
+        self.inner.as_std_refs()
+    }
+
+    /// Returns reference to element of inner tuple at index `I`. Index out of range fails to compile.
+    /// # Example
+    /// ```
+    /// use borrow_as::{LifeRef, tuple::Get};
+    /// fn first_and_fourth<'r, T>(r: &'r LifeRef<'_, T>) -> (&'r <T as Get<0>>::Item, &'r <T as Get<3>>::Item) where
+    /// T: Get<0> + Get<3> {
+    ///     (r.get::<0>(), r.get::<3>())
+    /// }
+    ///
+    /// let s = String::from("Referenced");
+    /// let mut x = 0;
+    /// let r = LifeRef::wrap_ref(&s[..3]).add_ref(&1).add_ref(&2).add_mut(&mut x);
+    /// let (first, fourth) = first_and_fourth(&r);
+    /// fourth.set(3);
+    /// assert_eq!(*first, "Ref");
+    /// assert_eq!(x, 3);
+    pub fn get<const I: usize>(&self) -> &T::Item where
+    T: tuple::Get<I> {
+        self.inner.get()
+    }
+
+    /// Converts wrapped value from one type to another, failing if `f` returns an error.
+    ///
+    /// Error is bound to `'a` as well, since it may hold elements of inner tuple.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// struct Port {
+    ///     addr: Ref<str>,
+    ///     port: u16,
+    /// }
+    ///
+    /// let good = String::from("localhost:8080");
+    /// let bad = String::from("localhost:http");
+    /// let parse = |(s,): (Ref<str>,)| {
+    ///     let port = s[s.find(':').unwrap() + 1..].parse();
+    ///     port.map(|port| Port { addr: s, port })
+    /// };
+    ///
+    /// let p = LifeRef::wrap_ref(good.as_str()).try_map_life(parse).unwrap();
+    /// assert_eq!(p.addr, "localhost:8080");
+    /// assert_eq!(p.port, 8080);
+    /// assert!(LifeRef::wrap_ref(bad.as_str()).try_map_life(parse).is_err());
+    pub fn try_map_life<U, E>(self, f: impl FnOnce(T) -> Result<U, E>) -> Result<LifeRef<'a, U>, LifeRef<'a, E>> {
+        match f(self.inner) {
+            Ok(inner) => Ok(LifeRef {
+                inner,
+                phantom: PhantomData,
+            }),
+            Err(inner) => Err(LifeRef {
+                inner,
+                phantom: PhantomData,
+            }),
+        }
+    }
+
+    /// Returns number of elements of inner tuple, same as `tuple::TupleLen::LEN`.
+    ///
+    /// Note: it's not named `len` so that `len` of inner arrays and vectors stays reachable through `Deref`.
+    /// # Example
+    /// ```
+    /// use borrow_as::{LifeRef, tuple::TupleLen};
+    /// fn assert_len<T: TupleLen>(_: &LifeRef<'_, T>, len: usize) {
+    ///     assert_eq!(T::LEN, len);
+    /// }
+    ///
+    /// let (a, b, c, d) = (1, 2, 3, 4);
+    /// let r = LifeRef::wrap_ref(&a).add_ref(&b).add_ref(&c).add_ref(&d);
+    /// assert_len(&LifeRef::empty(), 0);
+    /// assert_len(&LifeRef::wrap_ref(&a), 1);
+    /// assert_len(&r, 4);
+    /// assert_eq!(r.arity(), 4);
+    /// assert_eq!(LifeRef::wrap_ref_array([&a, &b]).len(), 2);
+    pub const fn arity(&self) -> usize where
+    T: tuple::TupleLen {
+        T::LEN
+    }
+
+    /// Applies `f` to every element of inner tuple whose elements are all of the same type, collecting results into array.
+    /// # Example
+    /// ```
+    /// let (a, b, c) = (1, 2, 3);
+    /// let r = borrow_as::LifeRef::wrap_ref(&a).add_ref(&b).add_ref(&c);
+    /// let values = r.map_each(|x| **x);
+    /// assert_eq!(values, [1, 2, 3]);
+    /// assert_eq!(values.iter().sum::<i32>(), 6);
+    pub fn map_each<const N: usize, U>(&self, f: impl FnMut(&T::Item) -> U) -> [U; N] where
+    T: tuple::Homogeneous<N> {
+        self.inner.each_ref().map(f)
+    }
+
+    /// Converts immutable reference at index `I` from one type to another, leaving other elements untouched.
+    /// Index out of range fails to compile.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// let a = 1;
+    /// let s = String::from(" 42 ");
+    /// let mut c = false;
+    /// let r = LifeRef::
+    ///     wrap_ref(&a)
+    ///     .add_ref(s.as_str())
+    ///     .add_mut(&mut c)
+    ///     .map_ref::<1, _, _>(|s| s.trim().parse::<i32>());
+    ///
+    /// assert_eq!(r.0, &1);
+    /// assert_eq!(r.1, Ok(42));
+    /// r.2.set(true);
+    /// assert!(c);
+    /// ```
+    /// ```compile_fail
+    /// let r = borrow_as::LifeRef::wrap_ref(&0).add_ref(&1);
+    /// let r = r.map_ref::<2, _, _>(|x| *x);
+    pub fn map_ref<const I: usize, U, V>(self, f: impl FnOnce(Ref<U>) -> V) -> LifeRef<'a, <T as tuple::Map<I, V>>::Output> where
+    T: tuple::Map<I, V, Item = Ref<U>>,
+    U: ?Sized {
+        LifeRef {
+            inner: self.inner.map(f),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Converts mutable reference at index `I` from one type to another, leaving other elements untouched.
+    /// Index out of range fails to compile.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// struct Counter(Mut<u32>);
+    ///
+    /// impl Counter {
+    ///     fn incr(&self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
+    ///
+    /// let a = 1;
+    /// let mut n = 0;
+    /// let c = 'c';
+    /// let r = LifeRef::
+    ///     wrap_ref(&a)
+    ///     .add_mut(&mut n)
+    ///     .add_ref(&c)
+    ///     .map_mut::<1, _, _>(Counter);
+    ///
+    /// r.1.incr();
+    /// r.1.incr();
+    /// assert_eq!(r.0, &1);
+    /// assert_eq!(r.2, &'c');
+    /// assert_eq!(n, 2);
+    pub fn map_mut<const I: usize, U, V>(self, f: impl FnOnce(Mut<U>) -> V) -> LifeRef<'a, <T as tuple::Map<I, V>>::Output> where
+    T: tuple::Map<I, V, Item = Mut<U>>,
+    U: ?Sized {
+        LifeRef {
+            inner: self.inner.map(f),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Replaces immutable reference at index `I` with `r` of the same type, keeping tuple shape.
+    /// Index out of range fails to compile.
+    /// # Example
+    /// ```
+    /// let (a, b, c, d) = (1, 2, 3, 4);
+    /// let r = borrow_as::LifeRef::wrap_ref(&a).add_ref(&b).add_ref(&c);
+    /// let r = r.set_ref::<1, _>(&d);
+    /// assert_eq!(r.0, &1);
+    /// assert_eq!(r.1, &4);
+    /// assert_eq!(r.2, &3);
+    pub fn set_ref<const I: usize, U>(self, r: &'a U) -> Self where
+    T: tuple::Map<I, Ref<U>, Item = Ref<U>, Output = T>,
+    U: 'a + ?Sized {
+        self.map_ref::<I, _, _>(|_| Ref::new(r))
+    }
+
+    /// Replaces mutable reference at index `I` with `r` of the same type, keeping tuple shape.
+    /// Index out of range fails to compile.
+    /// # Example
+    /// ```
+    /// let (mut a, mut b) = (1, 2);
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut a);
+    /// r.0.set(3);
+    /// let r = r.set_mut::<0, _>(&mut b);
+    /// r.0.set(4);
+    /// assert_eq!((a, b), (3, 4));
+    pub fn set_mut<const I: usize, U>(self, r: &'a mut U) -> Self where
+    T: tuple::Map<I, Mut<U>, Item = Mut<U>, Output = T>,
+    U: 'a + ?Sized {
+        self.map_mut::<I, _, _>(|_| Mut::from_mut(r))
+    }
+
+    /// Removes element at index `I` from inner tuple, returning it alongside the remaining elements, both still bound to `'a`.
+    /// Index out of range fails to compile.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// let (a, b, c) = (1, 2, 3);
+    /// let mut d = 4;
+    /// let r = LifeRef::wrap_ref(&a).add_ref(&b).add_ref(&c).add_mut(&mut d);
+    ///
+    /// let (r, m) = r.remove::<3>();
+    /// m.set(5);
+    /// let (r, b) = r.remove::<1>();
+    /// assert_eq!(*b, &2);
+    /// let (r, a) = r.remove::<0>();
+    /// assert_eq!(*a, &1);
+    /// let r: LifeRef<'_, (Ref<i32>,)> = r;
+    /// assert_eq!(r.0, &3);
+    /// assert_eq!(d, 5);
+    pub fn remove<const I: usize>(self) -> (LifeRef<'a, T::Rest>, LifeRef<'a, T::Item>) where
+    T: tuple::Remove<I> {
+        let (rest, item) = self.inner.remove();
+        let rest = LifeRef {
+            inner: rest,
+            phantom: PhantomData,
+        };
+        let item = LifeRef {
+            inner: item,
+            phantom: PhantomData,
+        };
+        (rest, item)
+    }
+
+    /// Converts mutable reference at index `I` into immutable one, leaving other elements untouched.
+    /// Index out of range fails to compile.
+    ///
+    /// # Safety
+    /// Same as `Mut::into_ref`: other `Mut` handles to the same value or its parts, e.g. made with `Mut::slice`,
+    /// may exist, and none of them may be used for as long as the resulting `Ref` or handles derived from it are.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// let a = 1;
+    /// let mut s = String::from("Unaltered");
+    /// let r = LifeRef::wrap_ref(&a).add_mut(&mut s);
+    ///
+    /// let mut s1 = r.1.take();
+    /// s1.replace_range(..3, "A");
+    /// r.1.set(s1);
+    ///
+    /// // SAFETY: no other handle to `s` exists.
+    /// let r: LifeRef<'_, (Ref<i32>, Ref<String>)> = unsafe { r.downgrade::<1, _>() };
+    /// assert_eq!(r.0, &1);
+    /// assert_eq!(r.1, "Altered");
+    pub unsafe fn downgrade<const I: usize, U>(self) -> LifeRef<'a, <T as tuple::Map<I, Ref<U>>>::Output> where
+    T: tuple::Map<I, Ref<U>, Item = Mut<U>>,
+    U: ?Sized {
+        self.map_mut::<I, _, _>(|m| Ref::new(m.as_ptr()))
+    }
+
+    /// Splits inner tuple into first `I` elements and the rest. Index out of range fails to compile.
+    /// # Example
+    /// ```
+    /// use borrow_as::LifeRef as Life;
+    /// let r = Life::from((1, 2, 3, 4, 5));
+    ///
+    /// let (left, right) = r.split_at::<0>();
+    /// assert_eq!(*left, ());
+    /// assert_eq!(*right, (1, 2, 3, 4, 5));
+    ///
+    /// let (left, right) = r.split_at::<2>();
+    /// assert_eq!(*left, (1, 2));
+    /// assert_eq!(*right, (3, 4, 5));
+    ///
+    /// let (left, right) = r.split_at::<5>();
+    /// assert_eq!(*left, (1, 2, 3, 4, 5));
+    /// assert_eq!(*right, ());
+    pub fn split_at<const I: usize>(self) -> (LifeRef<'a, T::Left>, LifeRef<'a, T::Right>) where
+    T: tuple::SplitAt<I> {
+        let (left, right) = self.inner.split_at();
+        let left = LifeRef {
+            inner: left,
+            phantom: PhantomData,
+        };
+        let right = LifeRef {
+            inner: right,
+            phantom: PhantomData,
+        };
+        (left, right)
+    }
+
+    /// Exchanges elements of inner tuple at indices `I` and `J`, together with their types.
+    /// Swapping element with itself leaves the tuple untouched. Index out of range fails to compile.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// let s = String::from("Referenced");
+    /// let a = 1;
+    /// let mut c = 'c';
+    /// let r = LifeRef::wrap_ref(s.as_str()).add_ref(&a).add_mut(&mut c);
+    ///
+    /// let r: LifeRef<'_, (Mut<char>, Ref<i32>, Ref<str>)> = r.swap::<0, 2>();
+    /// r.0.set('d');
+    /// assert_eq!(r.1, &1);
+    /// assert_eq!(r.2, "Referenced");
+    /// let r: LifeRef<'_, (Ref<str>, Ref<i32>, Mut<char>)> = r.swap::<2, 0>().swap::<1, 1>();
+    /// assert_eq!(r.0, "Referenced");
+    /// assert_eq!(c, 'd');
+    /// ```
+    /// ```compile_fail
+    /// let r = borrow_as::LifeRef::wrap_ref(&0).add_ref(&1);
+    /// let r = r.swap::<0, 2>();
+    pub fn swap<const I: usize, const J: usize>(self) -> LifeRef<'a, T::Output> where
+    T: tuple::Swap<I, J> {
+        LifeRef {
+            inner: self.inner.swap(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Unnests one level of inner tuple whose first element is a tuple, as produced by `add_life` after `wrap_life`.
+    /// Deeper nesting can be unnested by calling it repeatedly. Result longer than 16 elements fails to compile.
+    /// # Example
+    /// ```
+    /// use borrow_as::LifeRef as Life;
+    /// #[derive(Debug, PartialEq)]
+    /// struct A;
+    /// #[derive(Debug, PartialEq)]
+    /// struct B;
+    /// #[derive(Debug, PartialEq)]
+    /// struct C;
+    /// #[derive(Debug, PartialEq)]
+    /// struct D;
+    /// let ab = Life::from(A).wrap_life().add_life(Life::from(B));
+    /// let abc: Life<'_, ((A, B), C)> = ab.wrap_life().add_life(Life::from(C));
+    /// let abc: Life<'_, (A, B, C)> = abc.flatten();
+    /// assert_eq!(*abc, (A, B, C));
+    ///
+    /// let abcd = abc.split_at::<2>().0.wrap_life().add_life(Life::from(C)).wrap_life().add_life(Life::from(D));
+    /// let abcd: Life<'_, (A, B, C, D)> = abcd.flatten().flatten();
+    /// assert_eq!(*abcd, (A, B, C, D));
+    pub fn flatten(self) -> LifeRef<'a, T::Output> where
+    T: tuple::Flatten {
+        LifeRef {
+            inner: self.inner.flatten(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Extracts inner value, consuming `LifeRef`.
+    ///
+    /// # Safety
+    /// `Ref` and `Mut` carry no lifetime, so once extracted they aren't tied to `'a` anymore.
+    /// None of them, nor handles derived from them, may be used after `'a` ends. Use `scope` or `map_life` to keep them bound.
+    /// # Example
+    /// ```
+    /// let a = 1;
+    /// let mut b = 2;
+    /// let r = borrow_as::LifeRef::wrap_ref(&a).add_mut(&mut b);
+    /// // SAFETY: `a1` and `b1` are used only while `a` and `b` are borrowed.
+    /// let (a1, b1) = unsafe { r.into_inner() };
+    /// b1.set(*a1);
+    /// assert_eq!(b, 1);
+    pub unsafe fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns mutable reference to inner value, e.g. to reorder elements in place.
+    ///
+    /// # Safety
+    /// Elements written through the reference must be valid for the whole `'a`: handles taken from a shorter-lived
+    /// `LifeRef`, e.g. by swapping with its inner value, would dangle once it ends.
+    /// # Example
+    /// ```
+    /// let (a, b) = (1, 2);
+    /// let mut r = borrow_as::LifeRef::wrap_ref(&a).add_ref(&b);
+    /// // SAFETY: elements are only swapped with each other.
+    /// let (x, y) = unsafe { r.inner_mut() };
+    /// core::mem::swap(x, y);
+    /// assert_eq!(r.0, &2);
+    /// assert_eq!(r.1, &1);
+    pub unsafe fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Runs `f` with reference to inner value and returns its result, consuming `LifeRef` so it can't escape.
+    /// # Example
+    /// ```
+    /// use borrow_as::*;
+    /// struct X {
+    ///     v: Vec<u32>,
+    ///     n: u32,
+    /// }
+    ///
+    /// impl X {
+    ///     fn with_view<R>(&mut self, f: impl FnOnce(&(Ref<[u32]>, Mut<u32>)) -> R) -> R {
+    ///         LifeRef::wrap_ref(self.v.as_slice()).add_mut(&mut self.n).scope(f)
+    ///     }
+    /// }
+    ///
+    /// let mut x = X { v: vec![1, 2, 3], n: 0 };
+    /// let sum = x.with_view(|(v, n)| {
+    ///     n.set(v.len() as u32);
+    ///     v.iter().sum::<u32>()
+    /// });
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(x.n, 3);
+    pub fn scope<R>(self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.inner)
+    }
+
+    /// Runs `f` with reference to inner value and returns `self` unchanged, e.g. for logging or assertions mid-chain.
+    /// # Example
+    /// ```
+    /// let (a, mut b) = (1, 2);
+    /// let mut seen = 0;
+    /// let r = borrow_as::LifeRef::wrap_ref(&a)
+    ///     .inspect(|(a,)| seen = **a)
+    ///     .add_mut(&mut b);
+    /// r.1.set(*r.0 + seen);
+    /// assert_eq!(b, 2);
+    pub fn inspect(self, f: impl FnOnce(&T)) -> Self {
+        f(&self.inner);
+        self
+    }
+}
+
+/// Iterates over wrapped references.
+/// # Example
+/// ```
+/// let (a, b, c, d) = (1, 2, 3, 4);
+/// let r = borrow_as::LifeRef::wrap_ref_array([&a, &b, &c, &d]);
+/// let it = r.into_iter();
+/// assert_eq!(it.len(), 4);
+/// assert_eq!(it.map(|x| *x).collect::<Vec<_>>(), [1, 2, 3, 4]);
+///
+/// let e = borrow_as::LifeRef::wrap_ref_array([] as [&i32; 0]);
+/// assert_eq!(e.into_iter().count(), 0);
+impl<'a, T: ?Sized, const N: usize> IntoIterator for LifeRef<'a, [Ref<T>; N]> {
+    type Item = Ref<T>;
+    type IntoIter = core::array::IntoIter<Ref<T>, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.inner)
+    }
+}
+
+/// Iterates over wrapped references.
+/// # Example
+/// ```
+/// let v = vec![1, 2, 3];
+/// let r = borrow_as::LifeRef::wrap_ref_vec(v.iter().collect());
+/// for (x, y) in r.into_iter().zip(&v) {
+///     assert_eq!(x, y);
+/// }
+#[cfg(feature = "alloc")]
+impl<'a, T: ?Sized> IntoIterator for LifeRef<'a, alloc::vec::Vec<Ref<T>>> {
+    type Item = Ref<T>;
+    type IntoIter = alloc::vec::IntoIter<Ref<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+/// Formats inner value only, with `Ref` and `Mut` showing referenced values.
+/// # Example
+/// ```
+/// let a = 1;
+/// let mut b = String::from("two");
+/// let r = borrow_as::LifeRef::wrap_ref(&a).add_mut(&mut b);
+/// assert_eq!(format!("{:?}", r), r#"(Ref(1), Mut("two"))"#);
+impl<T: fmt::Debug> fmt::Debug for LifeRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Lists every element with `Display` of `Ref` and `Mut`, showing mutability of each.
+/// # Example
+/// ```
+/// let s = String::from("abc");
+/// let mut n = 42;
+/// let r = borrow_as::LifeRef::wrap_ref(s.as_str()).add_mut(&mut n);
+/// assert_eq!(r.to_string(), r#"LifeRef { Ref "abc", Mut 42 }"#);
+/// assert_eq!(borrow_as::LifeRef::empty().to_string(), "LifeRef {}");
+impl<T: tuple::DisplayElements> fmt::Display for LifeRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LifeRef ")?;
+        self.inner.fmt_elements(f)
+    }
+}
+
+impl<T> From<T> for LifeRef<'_, T> {
+    fn from(t: T) -> Self {
+        Self {
+            inner: t,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Wraps immutable reference as single `Ref` keeping its lifetime, same as `wrap_ref(r).map_life(|(r,)| r)`.
+///
+/// `Ref` itself has no lifetime, so there's no `From<&T>` for it directly.
+/// # Example
+/// ```
+/// use borrow_as::{LifeRef, Ref};
+/// let x = 42;
+/// let r: LifeRef<'_, Ref<i32>> = (&x).into();
+/// assert_eq!(*r.as_target() + 1, 43);
+impl<'a, T: ?Sized> From<&'a T> for LifeRef<'a, Ref<T>> {
+    fn from(r: &'a T) -> Self {
+        Self {
+            inner: Ref::new(r),
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Wraps mutable reference as single `Mut` keeping its lifetime, same as `wrap_mut(r).map_life(|(r,)| r)`.
+///
+/// `Mut` itself has no lifetime, so there's no `From<&mut T>` for it directly.
+/// # Example
+/// ```
+/// use borrow_as::{LifeRef, Mut};
+/// let mut x = 42;
+/// let r: LifeRef<'_, Mut<i32>> = (&mut x).into();
+/// r.set(r.get() + 1);
+/// assert_eq!(x, 43);
+impl<'a, T: ?Sized> From<&'a mut T> for LifeRef<'a, Mut<T>> {
+    fn from(r: &'a mut T) -> Self {
+        Self {
+            inner: Mut::from_mut(r),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Deref for LifeRef<'a, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T> Borrow<T> for LifeRef<'_, T> {
+    #[inline(always)]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+/// Serializes transparently as inner value.
+/// # Example
+/// ```
+/// use borrow_as::*;
+/// #[derive(serde::Serialize)]
+/// struct View {
+///     s: Ref<str>,
+///     v: Mut<Vec<u8>>,
+/// }
+///
+/// let s = String::from("Referenced");
+/// let mut v = vec![1, 2, 3];
+/// let view = LifeRef::wrap_ref(s.as_str()).add_mut(&mut v).map_life(|(s, v)| View { s, v });
+/// let json = serde_json::to_string(&view).unwrap();
+/// assert_eq!(json, r#"{"s":"Referenced","v":[1,2,3]}"#);
+/// let back: serde_json::Value = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back["v"][2], 3);
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for LifeRef<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+/// Owner of a value handing out `LifeRef` views into it, so views can be made inline without writing accessor methods.
+/// # Example
+/// ```
+/// use borrow_as::*;
+/// struct X {
+///     s: String,
+///     v: Vec<u32>,
+///     n: u32,
+/// }
+///
+/// struct A {
+///     s: Ref<str>,
+///     v: Ref<[u32]>,
+/// }
+///
+/// struct B {
+///     v: Ref<[u32]>,
+///     n: Mut<u32>,
+/// }
+///
+/// let mut cell = LifeCell::new(X { s: String::from("Referenced"), v: vec![1, 2, 3], n: 0 });
+/// let a = cell.view(|x| LifeRef::wrap_ref(x.s.as_str()).add_ref(x.v.as_slice()).map_life(|(s, v)| A { s, v }));
+/// assert_eq!(a.s, "Referenced");
+///
+/// let ab = cell.view_mut(|x| {
+///     let X { s, v, n } = x;
+///     let a = LifeRef::wrap_ref(s.as_str()).add_ref(v.as_slice()).map_life(|(s, v)| A { s, v });
+///     let b = LifeRef::wrap_ref(v.as_slice()).add_mut(n).map_life(|(v, n)| B { v, n });
+///     a.wrap_life().add_life(b)
+/// });
+/// let (a, b) = &*ab;
+/// b.n.set(b.v.iter().sum());
+/// assert_eq!(a.v, [1, 2, 3]);
+/// assert_eq!(cell.into_inner().n, 6);
+#[derive(Debug, Clone, Default)]
+pub struct LifeCell<T> {
+    inner: T,
+}
+
+impl<T> LifeCell<T> {
+    /// Takes ownership of `inner`.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Makes view borrowing owned value immutably.
+    pub fn view<'a, U>(&'a self, f: impl FnOnce(&'a T) -> LifeRef<'a, U>) -> LifeRef<'a, U> {
+        f(&self.inner)
+    }
+
+    /// Makes view borrowing owned value mutably.
+    pub fn view_mut<'a, U>(&'a mut self, f: impl FnOnce(&'a mut T) -> LifeRef<'a, U>) -> LifeRef<'a, U> {
+        f(&mut self.inner)
+    }
+
+    /// Extracts owned value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+/// Immutable reference.
+///
+/// With `field-names` feature it also keeps name of the field it was borrowed from by `borrow_fields!`, shown in `Debug`.
+#[cfg_attr(not(feature = "field-names"), repr(transparent))]
+pub struct Ref<T: ?Sized>(*const T, #[cfg(feature = "field-names")] Option<&'static str>);
+
+impl<T: ?Sized> Ref<T> {
+    const fn new(p: *const T) -> Self {
+        Ref(p, #[cfg(feature = "field-names")] None)
+    }
+
+    #[allow(unused_variables)]
+    const fn named(p: *const T, name: &'static str) -> Self {
+        Ref(p, #[cfg(feature = "field-names")] Some(name))
+    }
+
+    /// Returns name of the field this reference was borrowed from by `borrow_fields!`.
+    /// # Example
+    /// ```
+    /// struct S {
+    ///     a: i8,
+    /// }
+    ///
+    /// let s = S { a: 1 };
+    /// let r = borrow_as::borrow_fields!(s, ref a);
+    /// assert_eq!(r.0.field_name(), Some("a"));
+    /// assert_eq!(format!("{:?}", r.0), r#"Ref(i8 @ "a") = 1"#);
+    #[cfg(feature = "field-names")]
+    pub fn field_name(&self) -> Option<&'static str> {
+        self.1
+    }
+
+    /// Returns raw pointer to referenced value.
+    ///
+    /// Note: the pointer is valid only for the lifetime of `LifeRef` this reference came from,
+    /// and it's not allowed to write through it.
+    /// # Example
+    /// ```
+    /// let x = 42;
+    /// let r = borrow_as::LifeRef::wrap_ref(&x);
+    /// assert_eq!(r.0.as_ptr(), &x as *const _);
+    pub fn as_ptr(&self) -> *const T {
+        self.0
+    }
+
+    /// Checks whether both references point to the same object, regardless of `PartialEq`.
+    ///
+    /// Only data pointers are compared, so trait objects pointing to the same value are equal even with different vtables.
+    /// # Example
+    /// ```
+    /// let (a, b) = (1, 1);
+    /// let r = borrow_as::LifeRef::wrap_ref(&a).add_ref(&a).add_ref(&b);
+    /// assert!(r.0.ptr_eq(&r.1));
+    /// assert!(!r.0.ptr_eq(&r.2));
+    /// assert_eq!(r.0, r.2);
+    pub fn ptr_eq(&self, other: &Ref<T>) -> bool {
+        self.0 as *const () == other.0 as *const ()
+    }
+
+    /// Converts into reference to unsized type, such as a trait object, via coercion inside `f`.
+    ///
+    /// `f` must return reference derived from its argument, so it's usually a plain cast.
+    /// # Example
+    /// ```
+    /// use core::fmt::Debug;
+    /// let x = 42;
+    /// let r = borrow_as::LifeRef::wrap_ref(&x).map_life(|(r,)| (r.unsize(|x| x as &dyn Debug),));
+    /// assert_eq!(format!("{:?}", &*r.0), "42");
+    pub fn unsize<U: ?Sized>(self, f: impl for<'r> FnOnce(&'r T) -> &'r U) -> Ref<U> {
+        Ref::new(f(&*self) as *const U)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Ref<alloc::boxed::Box<T>> {
+    /// Returns immutable reference to boxed value, bound to the borrow of `self`.
+    /// # Example
+    /// ```
+    /// let b = Box::new(42);
+    /// let r = borrow_as::LifeRef::wrap_ref(&b);
+    /// assert_eq!(*r.0.as_inner(), &42);
+    pub fn as_inner(&self) -> LifeRef<'_, Ref<T>> {
+        LifeRef::from(&***self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Ref<alloc::rc::Rc<T>> {
+    /// Returns immutable reference to value behind `Rc`, bound to the borrow of `self`.
+    /// # Example
+    /// ```
+    /// let rc = std::rc::Rc::new(42);
+    /// let r = borrow_as::LifeRef::wrap_ref(&rc);
+    /// assert_eq!(*r.0.as_inner(), &42);
+    pub fn as_inner(&self) -> LifeRef<'_, Ref<T>> {
+        LifeRef::from(&***self)
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T: ?Sized> Ref<alloc::sync::Arc<T>> {
+    /// Returns immutable reference to value behind `Arc`, bound to the borrow of `self`.
+    /// # Example
+    /// ```
+    /// let arc = std::sync::Arc::new(42);
+    /// let r = borrow_as::LifeRef::wrap_ref(&arc);
+    /// assert_eq!(*r.0.as_inner(), &42);
+    pub fn as_inner(&self) -> LifeRef<'_, Ref<T>> {
+        LifeRef::from(&***self)
+    }
+}
+
+impl<T> Ref<MaybeUninit<T>> {
+    /// Returns immutable reference to contained value.
+    ///
+    /// # Safety
+    /// Value must be initialized, same as `MaybeUninit::assume_init_ref`.
+    /// # Example
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// let mut x = MaybeUninit::<u32>::uninit();
+    /// borrow_as::LifeRef::wrap_mut(&mut x).0.write(42);
+    /// let r = borrow_as::LifeRef::wrap_ref(&x);
+    /// assert_eq!(unsafe { r.0.assume_init_ref() }, &42);
+    pub unsafe fn assume_init_ref(&self) -> Ref<T> {
+        Ref::new(self.as_ptr() as *const T)
+    }
+}
+
+impl Ref<str> {
+    /// Returns referenced string slice, same as deref.
+    /// # Example
+    /// ```
+    /// let s = String::from("Referenced");
+    /// let r = borrow_as::LifeRef::wrap_ref(s.as_str());
+    /// assert!(r.0.as_str().starts_with("Ref"));
+    pub fn as_str(&self) -> &str {
+        self
+    }
+
+    /// Divides string slice into two at an index, returning immutable references to both parts bound to the borrow of `self`.
+    ///
+    /// # Panics
+    /// Panics if `mid` is not on a char boundary or is past the end of string, same as `str::split_at`.
+    /// # Example
+    /// ```
+    /// let s = String::from("Referenced");
+    /// let r = borrow_as::LifeRef::wrap_ref(s.as_str());
+    /// let parts = r.0.split_at(3);
+    /// assert_eq!(parts.0, "Ref");
+    /// assert_eq!(parts.1, "erenced");
+    /// ```
+    /// ```should_panic
+    /// let s = String::from("Ünicode");
+    /// let r = borrow_as::LifeRef::wrap_ref(s.as_str());
+    /// r.0.split_at(1);
+    pub fn split_at(&self, mid: usize) -> LifeRef<'_, (Ref<str>, Ref<str>)> {
+        let (head, tail) = str::split_at(self, mid);
+        LifeRef::wrap_ref(head).add_ref(tail)
+    }
+
+    /// Returns immutable reference to string slice with leading and trailing whitespace removed, bound to the borrow of `self`.
+    /// # Example
+    /// ```
+    /// let s = String::from("  Referenced\n");
+    /// let r = borrow_as::LifeRef::wrap_ref(s.as_str());
+    /// assert_eq!(*r.0.trim(), "Referenced");
+    pub fn trim(&self) -> LifeRef<'_, Ref<str>> {
+        LifeRef::from(str::trim(self))
+    }
+}
+
+impl<T> Ref<[T]> {
+    /// Returns referenced slice, same as deref. Counterpart of `as_slice_of_cells` of `Mut<[T]>`.
+    /// # Example
+    /// ```
+    /// let v = vec![3, 1, 2];
+    /// let r = borrow_as::LifeRef::wrap_ref(v.as_slice());
+    /// assert_eq!(r.0.as_slice().iter().max(), Some(&3));
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    /// Returns iterator over immutable references to slice elements, bound to the borrow of `self`.
+    /// # Example
+    /// ```
+    /// let v = vec![1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_ref(v.as_slice());
+    /// assert_eq!(r.0.iter_refs().map(|x| **x).sum::<i32>(), 6);
+    /// assert_eq!(*r.0.iter_refs().rev().next().unwrap(), &3);
+    /// assert_eq!(r.0.iter_refs().len(), 3);
+    ///
+    /// let e = borrow_as::LifeRef::wrap_ref(&[0u8; 0][..]);
+    /// assert!(e.0.iter_refs().next().is_none());
+    pub fn iter_refs(&self) -> impl DoubleEndedIterator<Item = LifeRef<'_, Ref<T>>> + ExactSizeIterator + '_ {
+        self.iter().map(LifeRef::from)
+    }
+
+    /// Returns iterator over pairs of immutable references to elements of both slices, bound to the borrows of both.
+    /// Stops at the end of shorter slice.
+    /// # Example
+    /// ```
+    /// let a = [1, 2, 3];
+    /// let b = ["a", "b", "c", "d", "e"];
+    /// let r = borrow_as::LifeRef::wrap_ref(&a[..]).add_ref(&b[..]);
+    /// let pairs: Vec<_> = r.0.zip_refs(&r.1).map(|p| (*p.0, *p.1)).collect();
+    /// assert_eq!(pairs, [(1, "a"), (2, "b"), (3, "c")]);
+    pub fn zip_refs<'b, U>(&'b self, other: &'b Ref<[U]>) -> impl ExactSizeIterator<Item = LifeRef<'b, (Ref<T>, Ref<U>)>> + 'b {
+        self.iter().zip(other.iter()).map(|(x, y)| LifeRef::wrap_ref(x).add_ref(y))
+    }
+
+    /// Returns immutable reference to sub-slice within `range`, bound to the borrow of `self`.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds, same as slice indexing.
+    /// # Example
+    /// ```
+    /// let v: Vec<u8> = (0..8).collect();
+    /// let r = borrow_as::LifeRef::wrap_ref(v.as_slice());
+    /// let (lo, hi) = (r.0.slice(..4), r.0.slice(4..));
+    /// assert_eq!(*lo, [0, 1, 2, 3]);
+    /// assert_eq!(*hi.slice(1..=2), [5, 6]);
+    /// assert!(r.0.slice(8..).is_empty());
+    /// ```
+    /// ```should_panic
+    /// let v = vec![1u8, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_ref(v.as_slice());
+    /// r.0.slice(2..4);
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> LifeRef<'_, Ref<[T]>> {
+        LifeRef::from(&self[(range.start_bound().cloned(), range.end_bound().cloned())])
+    }
+
+    /// Returns iterator over overlapping windows of length `size` bound to the borrow of `self`, same as `<[T]>::windows`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    /// # Example
+    /// ```
+    /// let v = [1, 2, 3, 4];
+    /// let r = borrow_as::LifeRef::wrap_ref(&v[..]);
+    /// let w: Vec<_> = r.0.windows(2).collect();
+    /// assert_eq!(w.len(), 3);
+    /// assert_eq!(*w[0], [1, 2]);
+    /// assert_eq!(*w[1], [2, 3]);
+    /// assert_eq!(*w[2], [3, 4]);
+    /// assert_eq!(r.0.windows(5).count(), 0);
+    /// ```
+    /// ```should_panic
+    /// let v = [1, 2];
+    /// borrow_as::LifeRef::wrap_ref(&v[..]).0.windows(0);
+    pub fn windows(&self, size: usize) -> impl DoubleEndedIterator<Item = LifeRef<'_, Ref<[T]>>> + ExactSizeIterator + '_ {
+        <[T]>::windows(self, size).map(LifeRef::from)
+    }
+
+    /// Returns immutable reference to slice element at position `i` bound to the borrow of `self`, or `None` if out of bounds.
+    /// # Example
+    /// ```
+    /// let v = vec![1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_ref(v.as_slice());
+    /// assert_eq!(*r.0.at(1).unwrap(), &2);
+    /// assert!(r.0.at(3).is_none());
+    pub fn at(&self, i: usize) -> Option<LifeRef<'_, Ref<T>>> {
+        self.get(i).map(LifeRef::from)
+    }
+
+    /// Binary searches sorted slice for `x`, returning immutable reference to matching element bound to the borrow of `self`,
+    /// or index where it could be inserted, same as `<[T]>::binary_search`.
+    /// # Example
+    /// ```
+    /// let v = vec![1, 3, 5, 7];
+    /// let r = borrow_as::LifeRef::wrap_ref(v.as_slice());
+    /// assert_eq!(*r.0.binary_search_ref(&5).unwrap(), &5);
+    /// assert_eq!(r.0.binary_search_ref(&4).err(), Some(2));
+    pub fn binary_search_ref(&self, x: &T) -> Result<LifeRef<'_, Ref<T>>, usize> where
+    T: Ord {
+        self.binary_search(x).map(|i| LifeRef::from(&self[i]))
+    }
+
+    /// Returns immutable reference to slice element at position `i` without bounds checking.
+    ///
+    /// # Safety
+    /// `i` must be less than slice length.
+    /// # Example
+    /// ```
+    /// let v = vec![1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_ref(v.as_slice());
+    /// assert_eq!(unsafe { r.0.at_unchecked(2) }, &3);
+    pub unsafe fn at_unchecked(&self, i: usize) -> Ref<T> {
+        Ref::new(self.get_unchecked(i))
+    }
+}
+
+/// `Ref<T>` behaves as `&T`, so it can be sent to other thread if `T` is `Sync`.
+unsafe impl<T: ?Sized + Sync> Send for Ref<T> {}
+
+/// `Ref<T>` behaves as `&T`, so it can be shared between threads if `T` is `Sync`.
+unsafe impl<T: ?Sized + Sync> Sync for Ref<T> {}
+
+impl<T: ?Sized, U: ?Sized> PartialEq<&U> for Ref<T> where T: PartialEq<U> {
+    #[inline(always)]
+    fn eq(&self, other: &&U) -> bool {
+        self.deref().eq(*other)
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U; N]> for Ref<[T]> where T: PartialEq<U> {
+    #[inline(always)]
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.deref().eq(&other[..])
+    }
+}
+
+/// Compares string slice with owned string, same as `&str == String`.
+/// # Example
+/// ```
+/// let (s, v) = (String::from("Referenced"), vec![1, 2, 3]);
+/// let mut t = String::from("Mutable");
+/// let r = borrow_as::LifeRef::wrap_ref(s.as_str()).add_ref(v.as_slice()).add_mut(t.as_mut_str());
+/// assert_eq!(r.0, String::from("Referenced"));
+/// assert_eq!(r.1, vec![1, 2, 3]);
+/// assert_eq!(r.2, String::from("Mutable"));
+#[cfg(feature = "alloc")]
+impl PartialEq<alloc::string::String> for Ref<str> {
+    #[inline(always)]
+    fn eq(&self, other: &alloc::string::String) -> bool {
+        self.deref() == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, U> PartialEq<alloc::vec::Vec<U>> for Ref<[T]> where T: PartialEq<U> {
+    #[inline(always)]
+    fn eq(&self, other: &alloc::vec::Vec<U>) -> bool {
+        self.deref().eq(&other[..])
+    }
+}
+
+/// Compares referenced values, not pointers.
+/// # Example
+/// ```
+/// let (a, b, c) = (1, 1, 2);
+/// let mut d = 2;
+/// let r = borrow_as::LifeRef::wrap_ref(&a).add_ref(&b).add_ref(&c).add_mut(&mut d);
+/// assert_eq!(r.0, r.1);
+/// assert_ne!(r.0, r.2);
+/// assert_eq!(r.2, r.3);
+/// assert_eq!(r.3, r.2);
+impl<T: ?Sized, U: ?Sized> PartialEq<Ref<U>> for Ref<T> where T: PartialEq<U> {
+    #[inline(always)]
+    fn eq(&self, other: &Ref<U>) -> bool {
+        self.deref().eq(other.deref())
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> PartialEq<Mut<U>> for Ref<T> where T: PartialEq<U> {
+    #[inline(always)]
+    fn eq(&self, other: &Mut<U>) -> bool {
+        unsafe { self.deref().eq(other.get()) }
+    }
+}
+
+impl<T: ?Sized> Eq for Ref<T> where T: Eq {}
+
+impl<T: ?Sized, U: ?Sized> PartialOrd<&U> for Ref<T> where T: PartialOrd<U> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &&U) -> Option<core::cmp::Ordering> {
+        self.deref().partial_cmp(*other)
+    }
+}
+
+/// Compares referenced values, not pointers.
+/// # Example
+/// ```
+/// use std::collections::BTreeSet;
+/// let (a, b, c) = (3, 1, 2);
+/// let r = borrow_as::LifeRef::wrap_ref_array([&a, &b, &c]);
+/// let mut v: Vec<_> = r.iter().collect();
+/// v.sort();
+/// assert_eq!(v.into_iter().map(|x| **x).collect::<Vec<_>>(), [1, 2, 3]);
+/// assert!(r[1] < r[2]);
+/// let set: BTreeSet<_> = r.iter().collect();
+/// assert_eq!(**set.first().unwrap(), &1);
+impl<T: ?Sized, U: ?Sized> PartialOrd<Ref<U>> for Ref<T> where T: PartialOrd<U> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Ref<U>) -> Option<core::cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<T: ?Sized> Ord for Ref<T> where T: Ord {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<T: ?Sized> Hash for Ref<T> where for<'a> &'a T: Hash {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deref().hash(state);
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Ref<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "field-names")]
+        if let Some(name) = self.1 {
+            return write!(f, "Ref({} @ {:?}) = {:?}", core::any::type_name::<T>(), name, self.deref());
+        }
+        f.debug_tuple("Ref")
+        .field(&self.deref())
+        .finish()
+    }
+}
+
+impl<T: ?Sized> fmt::Display for Ref<T> where for<'a> &'a T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let t = self.deref();
+        if f.alternate() {
+            f.debug_tuple("Ref")
+            .field(&t)
+            .finish()
+        }
+        else {
+            write!(f, "Ref {:?}", &t)
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for Ref<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        unsafe { &*self.0 }
+    }
+}
+
+/// Indexes referenced value.
+///
+/// Note: `Mut` doesn't forward `Index`, as the returned reference could be invalidated by writing through another handle.
+/// # Example
+/// ```
+/// let v = vec![1, 2, 3];
+/// let r = borrow_as::LifeRef::wrap_ref(&v).add_ref(v.as_slice());
+/// assert_eq!(r.0[1], 2);
+/// assert_eq!(r.1[2], 3);
+/// assert_eq!(&r.1[..2], [1, 2]);
+impl<T: ?Sized + Index<I>, I> Index<I> for Ref<T> {
+    type Output = T::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &T::Output {
+        &self.deref()[index]
+    }
+}
+
+/// Adds referenced values.
+/// # Example
+/// ```
+/// let (a, b) = (1, 2);
+/// let r = borrow_as::LifeRef::wrap_ref(&a).add_ref(&b);
+/// assert_eq!(&r.0 + &r.1, 3);
+impl<T: Copy + Add> Add for &Ref<T> {
+    type Output = T::Output;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> T::Output {
+        **self + **rhs
+    }
+}
+
+/// Subtracts referenced values.
+/// # Example
+/// ```
+/// let (a, b) = (5, 2);
+/// let r = borrow_as::LifeRef::wrap_ref(&a).add_ref(&b);
+/// assert_eq!(&r.0 - &r.1, 3);
+impl<T: Copy + Sub> Sub for &Ref<T> {
+    type Output = T::Output;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> T::Output {
+        **self - **rhs
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for Ref<T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+/// Forwards to `str::as_bytes`, as generic `AsRef<U>` forwarding would conflict with `AsRef<T>`.
+impl AsRef<[u8]> for Ref<str> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Allows passing `Ref<String>` where `impl AsRef<str>` is expected.
+/// # Example
+/// ```
+/// fn shout(s: impl AsRef<str>) -> String {
+///     s.as_ref().to_uppercase()
+/// }
+///
+/// let s = String::from("Referenced");
+/// let r = borrow_as::LifeRef::wrap_ref(&s);
+/// assert_eq!(shout(&r.0), "REFERENCED");
+#[cfg(feature = "alloc")]
+impl AsRef<str> for Ref<alloc::string::String> {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for Ref<alloc::string::String> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsRef<[T]> for Ref<alloc::vec::Vec<T>> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: ?Sized> Borrow<T> for Ref<T> {
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: ?Sized + serde::Serialize> serde::Serialize for Ref<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.deref().serialize(serializer)
+    }
+}
+
+/// Mutable reference via Cell.
+///
+/// Points to `UnsafeCell<T>` and exposes `Cell<T>` API through `Deref`, so any `?Sized` type can be referenced,
+/// including slices and trait objects.
+/// # Example
+/// ```
+/// let mut v = [1, 2, 3];
+/// let mut f: Box<dyn FnMut() -> i32> = Box::new(|| 4);
+/// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]).add_mut(&mut *f);
+/// r.0.as_slice_of_cells()[0].set(unsafe { r.1.update(|f| f()) });
+/// assert_eq!(v, [4, 2, 3]);
+/// ```
+/// Behaves as `&Cell<T>`, so it's neither `Send` nor `Sync`:
+/// other handles to the same cell left in current thread could write to it concurrently.
+/// ```compile_fail
+/// let mut x = 42;
+/// let r = borrow_as::LifeRef::wrap_mut(&mut x);
+/// std::thread::scope(|s| {
+///     s.spawn(move || r.0.set(0));
+/// });
+/// ```
+///
+/// With `field-names` feature it also keeps name of the field it was borrowed from by `borrow_fields!`, shown in `Debug`.
+#[cfg_attr(not(feature = "field-names"), repr(transparent))]
+pub struct Mut<T: ?Sized>(*const UnsafeCell<T>, #[cfg(feature = "field-names")] Option<&'static str>);
+
+impl<T: ?Sized> Mut<T> {
+    const fn new(p: *const UnsafeCell<T>) -> Self {
+        Mut(p, #[cfg(feature = "field-names")] None)
+    }
+
+    #[allow(unused_variables)]
+    const fn named(r: &mut T, name: &'static str) -> Self {
+        Mut(r as *mut T as *const UnsafeCell<T>, #[cfg(feature = "field-names")] Some(name))
+    }
+
+    /// Returns name of the field this reference was borrowed from by `borrow_fields!`.
+    /// # Example
+    /// ```
+    /// struct S {
+    ///     i: i8,
+    /// }
+    ///
+    /// let mut s = S { i: 1 };
+    /// let r = borrow_as::borrow_fields!(s, mut i);
+    /// assert_eq!(r.0.field_name(), Some("i"));
+    /// assert_eq!(format!("{:?}", r.0), r#"Mut(i8 @ "i") = 1"#);
+    #[cfg(feature = "field-names")]
+    pub fn field_name(&self) -> Option<&'static str> {
+        self.1
+    }
+
+    const fn from_mut(r: &mut T) -> Self {
+        Mut::new(r as *mut T as *const UnsafeCell<T>)
+    }
+
+    const fn from_cell(c: &Cell<T>) -> Self {
+        Mut::new(c as *const Cell<T> as *const UnsafeCell<T>)
+    }
+
+    unsafe fn get(&self) -> &T {
+        &*UnsafeCell::raw_get(self.0)
+    }
+
+    /// Returns immutable reference to referenced value, allowing to read non-`Copy` values without `take`.
+    ///
+    /// # Safety
+    /// Referenced value must not be written through any handle to the same cell while returned reference is alive.
+    /// # Example
+    /// ```
+    /// let mut v = vec![1u8, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v);
+    /// assert_eq!(unsafe { r.0.as_ref_unchecked() }.len(), 3);
+    pub unsafe fn as_ref_unchecked(&self) -> &T {
+        self.get()
+    }
+
+    /// Returns mutable reference to referenced value, allowing in-place mutation of non-`Copy` values.
+    ///
+    /// # Safety
+    /// Several `Mut` handles may point to the same cell, so exclusive borrow of this handle doesn't prove exclusive access to the value.
+    /// No other handle to the same cell may be used while returned reference is alive.
+    /// # Example
+    /// ```
+    /// let mut s = String::from("Unaltered");
+    /// let mut r = borrow_as::LifeRef::wrap_mut(&mut s);
+    /// // SAFETY: `r.0` is the only handle and inner tuple isn't swapped.
+    /// unsafe { r.inner_mut().0.get_mut() }.push_str(" no more");
+    /// assert_eq!(s, "Unaltered no more");
+    pub unsafe fn get_mut(&mut self) -> &mut T {
+        &mut *UnsafeCell::raw_get(self.0)
+    }
+
+    /// Applies `f` to referenced value in place, without temporarily replacing it.
+    ///
+    /// # Safety
+    /// Same as `get_mut`: no other handle to the same cell may be used until `f` returns, including from within `f`.
+    /// # Example
+    /// ```
+    /// let mut v = vec![1u8];
+    /// let mut s = String::from("Unaltered");
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v).add_mut(&mut s);
+    /// unsafe {
+    ///     r.0.update(|v| v.push(2));
+    ///     let len = r.1.update(|s| {
+    ///         s.replace_range(..3, "A");
+    ///         s.len()
+    ///     });
+    ///     assert_eq!(len, 7);
+    /// }
+    /// assert_eq!(v, [1, 2]);
+    /// assert_eq!(s, "Altered");
+    /// ```
+    /// For slices it gives whole `&mut [T]` at once, avoiding per-element `Cell` access:
+    /// ```
+    /// let mut v = [3, 1, 2];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// unsafe { r.0.update(|s| s.sort()) };
+    /// assert_eq!(v, [1, 2, 3]);
+    pub unsafe fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut *UnsafeCell::raw_get(self.0))
+    }
+
+    /// Applies `f` to shared reference to referenced value, reading non-`Copy` values without `take`. Read counterpart of `update`.
+    ///
+    /// # Safety
+    /// Same as `as_ref_unchecked`: referenced value must not be written through any handle to the same cell until `f` returns,
+    /// including from within `f`. The closure can't leak the reference, but it can capture other handles.
+    /// # Example
+    /// ```
+    /// let mut v = vec![1u8, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v);
+    /// assert_eq!(unsafe { r.0.peek(|v| v.len()) }, 3);
+    pub unsafe fn peek<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(self.get())
+    }
+
+    /// Returns raw pointer to referenced cell.
+    ///
+    /// Note: the pointer is valid only for the lifetime of `LifeRef` this reference came from,
+    /// and other `Mut` handles to the same cell may exist.
+    /// # Example
+    /// ```
+    /// let mut x = 42;
+    /// let p = &mut x as *mut i32;
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut x);
+    /// assert_eq!(r.0.as_cell_ptr() as *mut i32, p);
+    pub fn as_cell_ptr(&self) -> *const Cell<T> {
+        self.0 as *const Cell<T>
+    }
+
+    /// Checks whether both references point to the same cell, regardless of `PartialEq`.
+    ///
+    /// Only data pointers are compared, so trait objects pointing to the same value are equal even with different vtables.
+    /// # Example
+    /// ```
+    /// let (mut a, mut b) = (1, 1);
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut a).add_mut(&mut b);
+    /// assert!(r.0.ptr_eq(&r.0));
+    /// assert!(!r.0.ptr_eq(&r.1));
+    /// assert_eq!(r.0, r.1);
+    pub fn ptr_eq(&self, other: &Mut<T>) -> bool {
+        self.0 as *const () == other.0 as *const ()
+    }
+
+    /// Converts into reference to unsized type, such as a slice or trait object, via coercion inside `f`.
+    ///
+    /// `f` must return cell derived from its argument, so it's usually a plain cast.
+    /// # Example
+    /// ```
+    /// use std::cell::Cell;
+    /// let mut arr = [1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut arr).map_life(|(r,)| (r.unsize(|c| c as &Cell<[i32]>),));
+    /// r.0.fill(0);
+    /// assert_eq!(arr, [0; 3]);
+    pub fn unsize<U: ?Sized>(self, f: impl for<'r> FnOnce(&'r Cell<T>) -> &'r Cell<U>) -> Mut<U> {
+        Mut::from_cell(f(&self))
+    }
+
+    /// Converts into immutable reference to the same value, e.g. to pass a frozen snapshot downstream after mutation.
+    ///
+    /// # Safety
+    /// Several `Mut` handles may point to the same value, e.g. made with `slice` or `add_mut_wrapped`, so taking `self`
+    /// by value doesn't prove it's the only handle: others could still write through the cell while returned `Ref` is in use.
+    /// No other `Mut` handle to the same value may be used for as long as returned `Ref` or handles derived from it are. `LifeRef::downgrade` is the tuple-level counterpart.
+    /// # Example
+    /// ```
+    /// use borrow_as::{LifeRef, Ref};
+    /// let mut x = 1;
+    /// let r = LifeRef::wrap_mut(&mut x).map_life(|(m,)| {
+    ///     m.set(2);
+    ///     // SAFETY: `m` is the only handle and isn't used afterwards.
+    ///     (unsafe { m.into_ref() },)
+    /// });
+    /// let r: LifeRef<'_, (Ref<i32>,)> = r;
+    /// assert_eq!(r.0, &2);
+    pub unsafe fn into_ref(self) -> Ref<T> {
+        Ref::new(self.as_ptr())
+    }
+
+    /// Returns raw mutable pointer to referenced value.
+    ///
+    /// Note: the pointer is valid only for the lifetime of `LifeRef` this reference came from,
+    /// and other `Mut` handles to the same value may exist, so references created from it must not overlap with their use.
+    /// # Example
+    /// ```
+    /// let mut x = 42;
+    /// let p = &mut x as *mut i32;
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut x);
+    /// assert_eq!(r.0.as_ptr(), p);
+    /// unsafe { *r.0.as_ptr() = 1 };
+    /// assert_eq!(x, 1);
+    pub fn as_ptr(&self) -> *mut T {
+        UnsafeCell::raw_get(self.0)
+    }
+}
+
+impl<T> Mut<T> {
+    /// Captures current value into a snapshot with stable `Hash` and `Eq`.
+    /// # Example
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    /// fn hash(x: impl Hash) -> u64 {
+    ///     let mut h = DefaultHasher::new();
+    ///     x.hash(&mut h);
+    ///     h.finish()
+    /// }
+    ///
+    /// let mut x = 1;
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut x);
+    /// let frozen = r.0.freeze();
+    /// let (raw_before, frozen_before) = (hash(&r.0), hash(frozen));
+    ///
+    /// let mut set = std::collections::HashSet::new();
+    /// set.insert(frozen);
+    /// r.0.set(2);
+    ///
+    /// assert_ne!(hash(&r.0), raw_before);
+    /// assert_eq!(hash(frozen), frozen_before);
+    /// assert!(set.contains(&frozen));
+    /// assert_eq!(*frozen, 1);
+    /// ```
+    /// `Clone` runs on the value taken out of the cell, leaving `Default::default()` in its place meanwhile,
+    /// so it can't observe or interfere with writes through other handles:
+    /// ```
+    /// let mut s = String::from("Frozen");
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut s);
+    /// let frozen = r.0.freeze();
+    /// r.0.set(String::new());
+    /// assert_eq!(frozen.into_inner(), "Frozen");
+    pub fn freeze(&self) -> FrozenMut<T> where
+    T: Clone + Default {
+        FrozenMut(self.clone_out())
+    }
+
+    fn clone_out(&self) -> T where
+    T: Clone + Default {
+        let val = Cell::take(self);
+        let snapshot = val.clone();
+        Cell::set(self, val);
+        snapshot
+    }
+
+    /// Replaces referenced value with `val`, returning the old one.
+    /// # Example
+    /// ```
+    /// let mut s = String::from("Old");
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut s);
+    /// assert_eq!(r.0.replace(String::from("New")), "Old");
+    /// assert_eq!(s, "New");
+    pub fn replace(&self, val: T) -> T {
+        Cell::replace(self, val)
+    }
+
+    /// Takes referenced value, leaving `Default::default()` in its place.
+    /// # Example
+    /// ```
+    /// let mut v = vec![1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v);
+    /// assert_eq!(r.0.take(), [1, 2, 3]);
+    /// assert!(v.is_empty());
+    pub fn take(&self) -> T where
+    T: Default {
+        Cell::take(self)
+    }
+
+    /// Swaps referenced values of two mutable references. Swapping with itself does nothing.
+    /// # Example
+    /// ```
+    /// let mut a = String::from("A");
+    /// let mut b = String::from("B");
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut a).add_mut(&mut b);
+    /// r.0.swap(&r.1);
+    /// r.1.swap(&r.1);
+    /// assert_eq!(a, "B");
+    /// assert_eq!(b, "A");
+    pub fn swap(&self, other: &Mut<T>) {
+        Cell::swap(self, other)
+    }
+
+    /// Writes `new` if referenced value equals `current`, otherwise leaves it untouched and gives `new` back.
+    ///
+    /// Not atomic, but `PartialEq` runs on the value swapped out of the cell, holding `new` meanwhile, so it can't observe
+    /// or interfere with the update. The value is swapped back in if it doesn't match.
+    /// # Example
+    /// ```
+    /// #[derive(Debug, PartialEq)]
+    /// enum State {
+    ///     Idle,
+    ///     Running(String),
+    ///     Done,
+    /// }
+    ///
+    /// let mut s = State::Idle;
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut s);
+    /// let task = String::from("Task");
+    /// assert_eq!(r.0.compare_and_set(State::Idle, State::Running(task)), Ok(()));
+    /// assert_eq!(r.0.compare_and_set(State::Idle, State::Done), Err(State::Done));
+    /// assert_eq!(s, State::Running(String::from("Task")));
+    pub fn compare_and_set(&self, current: T, new: T) -> Result<(), T> where
+    T: PartialEq {
+        let actual = Cell::replace(self, new);
+        if actual == current {
+            Ok(())
+        }
+        else {
+            Err(Cell::replace(self, actual))
         }
     }
 }
 
-impl<'a, T: ?Sized> LifeRef<'a, (Mut<T>,)> {
-    /// Wraps mutable reference with inner value represented as 1-tuple for chaining with other methods.
+impl<T> Mut<MaybeUninit<T>> {
+    /// Initializes referenced value with `val`. Previous value is overwritten without being dropped.
     /// # Example
     /// ```
-    /// let mut v = vec![1, 2, 3];
-    /// let r_mut = borrow_as::LifeRef::wrap_mut(v.as_mut_slice());
-    /// let v_mut = r_mut.0.as_slice_of_cells();
-    /// v_mut[2].set(4);
-    /// assert_eq!(v, [1, 2, 4]);
-    pub fn wrap_mut(r: &'a mut T) -> Self {
-        Self {
-            inner: (Mut(Cell::from_mut(r)),),
-            phantom: PhantomData,
-        }
+    /// use std::mem::MaybeUninit;
+    /// let mut buf = [MaybeUninit::<u32>::uninit(); 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut buf[..]);
+    /// r.0.iter_muts().enumerate().for_each(|(i, x)| x.write(i as u32));
+    /// let r = borrow_as::LifeRef::wrap_ref(&buf[2]);
+    /// assert_eq!(unsafe { r.0.assume_init_ref() }, &2);
+    pub fn write(&self, val: T) {
+        self.set(MaybeUninit::new(val));
     }
 }
 
-impl<'a, T> LifeRef<'a, T> {
-    /// Wraps inner value into 1-tuple for chaining with other methods.
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Mut<alloc::boxed::Box<T>> {
+    /// Returns mutable reference to boxed value.
+    ///
+    /// # Safety
+    /// Returned reference points into current box, so the box must not be replaced or dropped through any handle
+    /// while returned reference is in use.
     /// # Example
     /// ```
-    /// let u = borrow_as::LifeRef::from(true);
-    /// assert!(*u);
-    /// let uu = u.wrap_life();
-    /// assert!(uu.0);
-    pub fn wrap_life(self) -> LifeRef<'a, (T,)> {
-        LifeRef {
-            inner: (self.inner,),
-            phantom: PhantomData,
-        }
+    /// let mut b = Box::new(1);
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut b);
+    /// let inner = unsafe { r.0.as_inner() };
+    /// inner.set(2);
+    /// assert_eq!(*b, 2);
+    pub unsafe fn as_inner(&self) -> Mut<T> {
+        let b = &mut *UnsafeCell::raw_get(self.0);
+        Mut::from_mut(&mut **b)
     }
 
-    /// Extends inner tuple by one element which represents passed immutable reference. Supports extending up to 16 elements.
+    /// Calls boxed closure in place, without `take` or `replace` of the box.
+    ///
+    /// `Mut` derefs to `Cell`, which gives no shared access to its value, so the closure can't be called through deref
+    /// as with `Ref<Box<dyn Fn()>>`.
+    ///
+    /// # Safety
+    /// Same as `peek`: the box must not be replaced or dropped through any handle while the closure runs,
+    /// including by the closure itself.
     /// # Example
     /// ```
-    /// let t = (0, 1);
-    /// let s = String::from("Referenced");
-    /// let r = borrow_as::LifeRef::wrap_ref(&t).add_ref(&s[..3]).add_ref(&42);
-    /// assert_eq!(r.0, &t);
-    /// assert_eq!(r.1, "Ref");
-    /// assert_eq!(r.2, &42);
-    pub fn add_ref<U>(self, r: &'a U) -> LifeRef<'a, T::Output> where
-    T: Append<Ref<U>>,
-    U: 'a + ?Sized {
-        let t = self.inner;
-        let v = t.append(Ref(r));
-        LifeRef {
-            inner: v,
-            phantom: PhantomData,
-        }
+    /// let mut f: Box<dyn Fn() -> i32> = Box::new(|| 5);
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut f);
+    /// assert_eq!(unsafe { r.0.call() }, 5);
+    /// r.0.set(Box::new(|| 6));
+    /// assert_eq!(unsafe { r.0.call() }, 6);
+    pub unsafe fn call<R>(&self) -> R where
+    T: Fn() -> R {
+        self.peek(|f| f())
     }
+}
 
-    /// Extends inner tuple by one element which represents passed mutable reference. Supports extending up to 16 elements.
+impl<T> Mut<[T]> {
+    fn from_cells(cells: &[Cell<T>]) -> Self {
+        Mut::new(cells as *const [Cell<T>] as *const UnsafeCell<[T]>)
+    }
+
+    /// Returns iterator over mutable references to slice elements, bound to the borrow of `self`.
     /// # Example
     /// ```
-    /// let mut t = (0, 1);
-    /// let mut s = String::from("Unaltered");
-    /// let r = borrow_as::LifeRef::wrap_mut(&mut t).add_mut(&mut s);
+    /// let mut v = vec![1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(v.as_mut_slice());
+    /// for (i, x) in r.0.iter_muts().enumerate() {
+    ///     x.set(x.get() * 10 + i);
+    /// }
+    /// assert_eq!(r.0.iter_muts().len(), 3);
+    /// assert_eq!(v, [10, 21, 32]);
+    pub fn iter_muts(&self) -> impl DoubleEndedIterator<Item = LifeRef<'_, Mut<T>>> + ExactSizeIterator + '_ {
+        self.as_slice_of_cells().iter().map(|c| LifeRef::from(Mut::from_cell(c)))
+    }
+
+    /// Collects mutable references to slice elements into a `Vec` bound to the borrow of `self`, e.g. to hand them out one per task.
+    /// # Example
+    /// ```
+    /// let mut v = [1, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// let handles = r.0.into_mut_vec();
+    /// for (i, x) in handles.iter().rev().enumerate() {
+    ///     x.set(x.get() * 10 + i as i32);
+    /// }
+    /// assert_eq!(handles.len(), 3);
+    /// assert_eq!(v, [12, 21, 30]);
+    #[cfg(feature = "alloc")]
+    pub fn into_mut_vec(&self) -> LifeRef<'_, alloc::vec::Vec<Mut<T>>> {
+        LifeRef::from(self.as_slice_of_cells().iter().map(|c| Mut::from_cell(c)).collect::<alloc::vec::Vec<_>>())
+    }
+
+    /// Returns Rayon parallel iterator over non-overlapping chunks of length `size`, same as `par_chunks_mut` of `&mut [T]`.
     ///
-    /// let (y, x) = r.0.get();
-    /// r.0.set((x, y));
+    /// `Mut` isn't `Send`, so chunks are plain `&mut [T]` borrowing `self`.
     ///
-    /// let mut r1 = r.1.take();
-    /// r1.replace_range(..3, "A");
-    /// r.1.set(r1);
+    /// # Safety
+    /// Same as `update`: no other handle to the same slice or its elements may be used while the iterator or chunks are alive.
     ///
-    /// assert_eq!(t, (1, 0));
-    /// assert_eq!(s, "Altered");
-    pub fn add_mut<U>(self, r: &'a mut U) -> LifeRef<'a, T::Output> where
-    T: Append<Mut<U>>,
-    U: 'a + ?Sized {
-        let t = self.inner;
-        let v = t.append(Mut(Cell::from_mut(r)));
-        LifeRef {
-            inner: v,
-            phantom: PhantomData,
-        }
+    /// # Panics
+    /// Panics if `size` is 0.
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "rayon")] {
+    /// use rayon::prelude::*;
+    /// let mut v = [1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// unsafe { r.0.par_chunks_mut(2) }.for_each(|c| c.iter_mut().for_each(|x| *x *= *x));
+    /// assert_eq!(v, [1.0, 4.0, 9.0, 16.0, 25.0]);
+    /// # }
+    #[cfg(feature = "rayon")]
+    pub unsafe fn par_chunks_mut(&self, size: usize) -> rayon::slice::ChunksMut<'_, T> where
+    T: Send {
+        rayon::slice::ParallelSliceMut::par_chunks_mut(&mut *UnsafeCell::raw_get(self.0), size)
     }
 
-    /// Extends inner tuple with extracted value from another `LifeRef`.
+    /// Returns iterator over non-overlapping chunks of length `size` bound to the borrow of `self`, same as `<[T]>::chunks_mut`.
+    /// The last chunk is shorter if `size` doesn't divide slice length.
     ///
-    /// Note: `other` can't outlive `self` and its lifetime will be shortened accordingly.
+    /// # Panics
+    /// Panics if `size` is 0.
     /// # Example
     /// ```
-    /// use borrow_as::LifeRef as Life;
-    /// struct A;
-    /// struct B;
-    /// struct C;
-    /// let a = Life::from(A).wrap_life();
-    /// let b = Life::from(B);
-    /// let c = Life::from(C);
-    /// let ab = a.add_life(b).wrap_life();
-    /// let abc: Life<'_, ((A, B), C)> = ab.add_life(c);
-    pub fn add_life<'b, U>(self, other: LifeRef<'b, U>) -> LifeRef<'a, T::Output> where
-    T: Append<U>,
-    'b: 'a {
-        let t = self.inner;
-        let v = t.append(other.inner);
-        LifeRef {
-            inner: v,
-            phantom: PhantomData,
-        }
+    /// let mut v = [0u8; 5];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// for (i, chunk) in r.0.chunks_mut(2).enumerate() {
+    ///     chunk.fill(i as u8 + 1);
+    /// }
+    /// assert_eq!(v, [1, 1, 2, 2, 3]);
+    /// ```
+    /// ```should_panic
+    /// let mut v = [0u8; 2];
+    /// borrow_as::LifeRef::wrap_mut(&mut v[..]).0.chunks_mut(0);
+    pub fn chunks_mut(&self, size: usize) -> impl DoubleEndedIterator<Item = LifeRef<'_, Mut<[T]>>> + ExactSizeIterator + '_ {
+        self.as_slice_of_cells()
+            .chunks(size)
+            .map(|c| LifeRef::from(Mut::from_cells(c)))
     }
-    
-    /// Converts wrapped value from one type to another.
+
+    /// Sets every element of slice to `val`.
     /// # Example
     /// ```
-    /// use borrow_as::*;
-    /// struct Test {
-    ///     x: Mut<String>,
-    ///     y: Ref<i32>,
-    ///     z: Mut<bool>,
-    /// }
-    /// 
-    /// let int = 42;
-    /// let mut string = String::from("Unaltered");
-    /// let mut flag = false;
-    /// let test = LifeRef::
-    ///     wrap_mut(&mut string)
-    ///     .add_ref(&int)
-    ///     .add_mut(&mut flag)
-    ///     .map_life(|(x, y, z)| Test { x, y, z });
-    /// 
-    /// let mut x = test.x.take();
-    /// x.replace_range(..3, "A");
-    /// test.x.set(x);
-    /// assert_eq!(test.y, &42);
-    /// test.z.set(!test.z.get());
-    /// 
-    /// assert_eq!(string, "Altered");
-    /// assert!(flag);
-    pub fn map_life<U>(self, f: impl Fn(T) -> U) -> LifeRef<'a, U> {
-        LifeRef {
-            inner: f(self.inner),
-            phantom: PhantomData,
-        }
+    /// let mut v = [1u32, 2, 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// r.0.fill(7);
+    /// assert_eq!(v, [7, 7, 7]);
+    pub fn fill(&self, val: T) where
+    T: Copy {
+        self.as_slice_of_cells().iter().for_each(|c| c.set(val));
     }
-}
 
-impl<T> From<T> for LifeRef<'_, T> {
-    fn from(t: T) -> Self {
-        Self {
-            inner: t,
-            phantom: PhantomData,
-        }
+    /// Copies elements from `src` into slice.
+    ///
+    /// # Panics
+    /// Panics if lengths differ, same as `<[T]>::copy_from_slice`.
+    /// # Example
+    /// ```
+    /// let mut v = [0u32; 4];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// r.0.slice(1..3).copy_from_slice(&[5, 6]);
+    /// assert_eq!(v, [0, 5, 6, 0]);
+    /// ```
+    /// ```should_panic
+    /// let mut v = [0u32; 4];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// r.0.copy_from_slice(&[1, 2]);
+    pub fn copy_from_slice(&self, src: &[T]) where
+    T: Copy {
+        let cells = self.as_slice_of_cells();
+        assert_eq!(cells.len(), src.len(), "source slice length does not match destination");
+        cells.iter().zip(src).for_each(|(c, x)| c.set(*x));
     }
-}
 
-impl<'a, T> Deref for LifeRef<'a, T> {
-    type Target = T;
+    /// Divides slice into two at an index, returning mutable references to both parts bound to the borrow of `self`.
+    /// Parts never overlap, so each can be written independently.
+    ///
+    /// # Panics
+    /// Panics if `mid > len`, same as `<[T]>::split_at_mut`.
+    /// # Example
+    /// ```
+    /// use borrow_as::Mut;
+    /// fn fill(s: &Mut<[u32]>, from: u32) {
+    ///     let len = s.iter_muts().len();
+    ///     if len == 1 {
+    ///         s.iter_muts().for_each(|x| x.set(from));
+    ///     }
+    ///     else if len > 1 {
+    ///         let mid = len / 2;
+    ///         let halves = s.split_at_mut(mid);
+    ///         fill(&halves.0, from);
+    ///         fill(&halves.1, from + mid as u32);
+    ///     }
+    /// }
+    ///
+    /// let mut v = [0; 5];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// fill(&r.0, 1);
+    /// let halves = r.0.split_at_mut(5);
+    /// halves.0.iter_muts().for_each(|x| x.set(x.get() * 10));
+    /// assert_eq!(halves.1.iter_muts().len(), 0);
+    /// assert_eq!(v, [10, 20, 30, 40, 50]);
+    /// ```
+    /// ```should_panic
+    /// let mut v = [0; 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// r.0.split_at_mut(4);
+    #[allow(clippy::type_complexity)]
+    pub fn split_at_mut(&self, mid: usize) -> LifeRef<'_, (Mut<[T]>, Mut<[T]>)> {
+        let (lo, hi) = self.as_slice_of_cells().split_at(mid);
+        LifeRef::from((Mut::from_cells(lo), Mut::from_cells(hi)))
+    }
 
-    #[inline(always)]
-    fn deref(&self) -> &T {
-        &self.inner
+    /// Returns mutable reference to sub-slice within `range`, bound to the borrow of `self`.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds, same as slice indexing.
+    /// # Example
+    /// ```
+    /// let mut v = [0u8; 6];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// let mid = r.0.slice(2..4);
+    /// mid.iter_muts().for_each(|x| x.set(1));
+    /// mid.slice(1..).iter_muts().for_each(|x| x.set(2));
+    /// assert_eq!(*r.0.slice(..3), [0, 0, 1]);
+    /// assert_eq!(v, [0, 0, 1, 2, 0, 0]);
+    /// ```
+    /// ```should_panic
+    /// let mut v = [0u8; 3];
+    /// let r = borrow_as::LifeRef::wrap_mut(&mut v[..]);
+    /// r.0.slice(..=3);
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> LifeRef<'_, Mut<[T]>> {
+        LifeRef::from(Mut::from_cells(&self.as_slice_of_cells()[(range.start_bound().cloned(), range.end_bound().cloned())]))
     }
 }
 
-impl<T> Borrow<T> for LifeRef<'_, T> {
+impl<T: ?Sized, U: ?Sized> PartialEq<&U> for Mut<T> where T: PartialEq<U> {
     #[inline(always)]
-    fn borrow(&self) -> &T {
-        self
+    fn eq(&self, other: &&U) -> bool {
+        unsafe { self.get().eq(*other) }
     }
 }
 
-/// Immutable reference.
-#[derive(Debug)]
-#[repr(transparent)]
-pub struct Ref<T: ?Sized>(*const T);
-
-impl<T: ?Sized, U: ?Sized> PartialEq<U> for Ref<T> where for<'a> &'a T: PartialEq<U> {
+impl<T, U, const N: usize> PartialEq<[U; N]> for Mut<[T]> where T: PartialEq<U> {
     #[inline(always)]
-    fn eq(&self, other: &U) -> bool {
-        self.deref().eq(other)
+    fn eq(&self, other: &[U; N]) -> bool {
+        unsafe { self.get().eq(&other[..]) }
     }
 }
 
-impl<T: ?Sized> Eq for Ref<T> where for<'a> &'a T: Eq + PartialEq<Self> {}
-
-impl<T: ?Sized, U: ?Sized> PartialOrd<U> for Ref<T> where for<'a> &'a T: PartialOrd<U> {
+#[cfg(feature = "alloc")]
+impl PartialEq<alloc::string::String> for Mut<str> {
     #[inline(always)]
-    fn partial_cmp(&self, other: &U) -> Option<core::cmp::Ordering> {
-        self.deref().partial_cmp(other)
+    fn eq(&self, other: &alloc::string::String) -> bool {
+        unsafe { self.get() == other }
     }
 }
 
-impl<T: ?Sized> Ord for Ref<T> where for<'a> &'a T: Ord + Eq + PartialOrd<Self> {
+#[cfg(feature = "alloc")]
+impl<T, U> PartialEq<alloc::vec::Vec<U>> for Mut<[T]> where T: PartialEq<U> {
     #[inline(always)]
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        self.deref().cmp(&other.deref())
+    fn eq(&self, other: &alloc::vec::Vec<U>) -> bool {
+        unsafe { self.get().eq(&other[..]) }
     }
 }
 
-impl<T: ?Sized> Hash for Ref<T> where for<'a> &'a T: Hash {
+impl<T: ?Sized, U: ?Sized> PartialEq<Mut<U>> for Mut<T> where T: PartialEq<U> {
     #[inline(always)]
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.deref().hash(state);
+    fn eq(&self, other: &Mut<U>) -> bool {
+        unsafe { self.get().eq(other.get()) }
     }
 }
 
-impl<T: ?Sized> fmt::Display for Ref<T> where for<'a> &'a T: fmt::Debug {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let t = self.deref();
-        if f.alternate() {
-            f.debug_tuple("Ref")
-            .field(&t)
-            .finish()
-        }
-        else {
-            write!(f, "Ref {:?}", &t)
-        }
+impl<T: ?Sized, U: ?Sized> PartialEq<Ref<U>> for Mut<T> where T: PartialEq<U> {
+    #[inline(always)]
+    fn eq(&self, other: &Ref<U>) -> bool {
+        unsafe { self.get().eq(other.deref()) }
     }
 }
 
-impl<T: ?Sized> Deref for Ref<T> {
-    type Target = T;
+impl<T: ?Sized> Eq for Mut<T> where T: Eq {}
 
+impl<T: ?Sized, U: ?Sized> PartialOrd<&U> for Mut<T> where T: PartialOrd<U> {
     #[inline(always)]
-    fn deref(&self) -> &T {
-        unsafe { &*self.0 }
-    }
-}
-
-impl<T: ?Sized> AsRef<T> for Ref<T> {
-    fn as_ref(&self) -> &T {
-        self
+    fn partial_cmp(&self, other: &&U) -> Option<core::cmp::Ordering> {
+        unsafe { self.get().partial_cmp(*other) }
     }
 }
 
-impl<T: ?Sized> Borrow<T> for Ref<T> {
-    fn borrow(&self) -> &T {
-        self
+/// Compares current values, not pointers.
+/// # Example
+/// ```
+/// let (mut a, mut b) = (2, 1);
+/// let r = borrow_as::LifeRef::wrap_mut(&mut a).add_mut(&mut b);
+/// let mut v = [&r.0, &r.1];
+/// v.sort();
+/// assert_eq!(*v[0], &1);
+/// assert!(r.1 < r.0);
+impl<T: ?Sized, U: ?Sized> PartialOrd<Mut<U>> for Mut<T> where T: PartialOrd<U> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Mut<U>) -> Option<core::cmp::Ordering> {
+        unsafe { self.get().partial_cmp(other.get()) }
     }
 }
 
-/// Mutable reference via Cell.
-#[derive(Debug)]
-#[repr(transparent)]
-pub struct Mut<T: ?Sized>(*const Cell<T>);
-
-impl<T: ?Sized> Mut<T> {
-    unsafe fn get(&self) -> &T {
-        (&mut *(self.0 as *mut Cell<T>)).get_mut()
+/// Ordering may change through this or any other handle to the same cell, so ordered collections of `Mut` break once values change.
+impl<T: ?Sized> Ord for Mut<T> where T: Ord {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        unsafe { self.get().cmp(other.get()) }
     }
 }
 
-impl<T: ?Sized, U: ?Sized> PartialEq<U> for Mut<T> where for<'a> &'a T: PartialEq<U> {
+/// Hashes current value, which may change through this or any other handle to the same cell.
+/// Using `Mut` as a key of hashed collection breaks it once the value changes, use `Mut::freeze` instead.
+impl<T: ?Sized> Hash for Mut<T> where for<'a> &'a T: Hash {
     #[inline(always)]
-    fn eq(&self, other: &U) -> bool {
-        unsafe { self.get().eq(other) }
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe { self.get().hash(state) };
     }
 }
 
-impl<T: ?Sized> Eq for Mut<T> where for<'a> &'a T: Eq + PartialEq<Self> {}
+/// Snapshot of value behind `Mut` taken at some point in time, produced by `Mut::freeze`.
+///
+/// Unlike `Mut`, its `Hash` and `Eq` are stable, so it can be used as a key of hashed collection.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub struct FrozenMut<T>(T);
 
-impl<T: ?Sized, U: ?Sized> PartialOrd<U> for Mut<T> where for<'a> &'a T: PartialOrd<U> {
-    #[inline(always)]
-    fn partial_cmp(&self, other: &U) -> Option<core::cmp::Ordering> {
-        unsafe { self.get().partial_cmp(other) }
+impl<T> FrozenMut<T> {
+    /// Extracts captured value.
+    pub fn into_inner(self) -> T {
+        self.0
     }
 }
 
-impl<T: ?Sized> Ord for Mut<T> where for<'a> &'a T: Ord + Eq + PartialOrd<Self> {
+impl<T> Deref for FrozenMut<T> {
+    type Target = T;
+
     #[inline(always)]
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        unsafe { self.get().cmp(&other.get()) }
+    fn deref(&self) -> &T {
+        &self.0
     }
 }
 
-impl<T: ?Sized> Hash for Mut<T> where for<'a> &'a T: Hash {
-    #[inline(always)]
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        unsafe { self.get().hash(state) };
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Mut<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "field-names")]
+        if let Some(name) = self.1 {
+            return write!(f, "Mut({} @ {:?}) = {:?}", core::any::type_name::<T>(), name, unsafe { self.get() });
+        }
+        f.debug_tuple("Mut")
+        .field(&unsafe { self.get() })
+        .finish()
     }
 }
 
@@ -444,7 +3684,39 @@ impl<T: ?Sized> Deref for Mut<T> {
 
     #[inline(always)]
     fn deref(&self) -> &Cell<T> {
-        unsafe { &*self.0 }
+        unsafe { &*self.as_cell_ptr() }
+    }
+}
+
+/// Adds current values, leaving both cells untouched.
+/// # Example
+/// ```
+/// let (mut a, mut b) = (1, 2);
+/// let r = borrow_as::LifeRef::wrap_mut(&mut a).add_mut(&mut b);
+/// r.0.set(&r.0 + &r.1);
+/// assert_eq!(a, 3);
+impl<T: Copy + Add> Add for &Mut<T> {
+    type Output = T::Output;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> T::Output {
+        Cell::get(self) + Cell::get(rhs)
+    }
+}
+
+/// Subtracts current values, leaving both cells untouched.
+/// # Example
+/// ```
+/// let (mut a, mut b) = (5, 2);
+/// let r = borrow_as::LifeRef::wrap_mut(&mut a).add_mut(&mut b);
+/// r.1.set(&r.0 - &r.1);
+/// assert_eq!(b, 3);
+impl<T: Copy + Sub> Sub for &Mut<T> {
+    type Output = T::Output;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> T::Output {
+        Cell::get(self) - Cell::get(rhs)
     }
 }
 
@@ -458,4 +3730,69 @@ impl<T: ?Sized> Borrow<Cell<T>> for Mut<T> {
     fn borrow(&self) -> &Cell<T> {
         self
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "serde")]
+impl<T: ?Sized + serde::Serialize> serde::Serialize for Mut<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        unsafe { self.get().serialize(serializer) }
+    }
+}
+
+/// Pinned mutable reference, added with `LifeRef::add_pin_mut`.
+///
+/// Unlike `Mut`, it's not backed by `Cell`, so the only mutable access is `Pin<&mut T>` through
+/// exclusive borrow, e.g. from `LifeRef::inner_mut`, which never allows moving the value out.
+/// # Example
+/// ```
+/// use std::marker::PhantomPinned;
+/// use std::pin::{pin, Pin};
+/// struct Counter {
+///     n: u32,
+///     _pin: PhantomPinned,
+/// }
+///
+/// impl Counter {
+///     fn bump(self: Pin<&mut Self>) {
+///         unsafe { self.get_unchecked_mut().n += 1 };
+///     }
+/// }
+///
+/// let s = String::from("Referenced");
+/// let mut c = pin!(Counter { n: 0, _pin: PhantomPinned });
+/// let mut r = borrow_as::LifeRef::wrap_ref(s.as_str()).add_pin_mut(c.as_mut());
+/// // SAFETY: inner tuple isn't swapped.
+/// let (_, p) = unsafe { r.inner_mut() };
+/// p.as_mut().bump();
+/// p.as_mut().bump();
+/// assert_eq!(r.1.n, 2);
+/// assert_eq!(r.0, "Referenced");
+pub struct PinMut<T: ?Sized>(*mut T);
+
+impl<T: ?Sized> PinMut<T> {
+    /// Reborrows as `Pin<&mut T>`.
+    pub fn as_mut(&mut self) -> Pin<&mut T> {
+        unsafe { Pin::new_unchecked(&mut *self.0) }
+    }
+
+    /// Reborrows as `Pin<&T>`.
+    pub fn as_ref(&self) -> Pin<&T> {
+        unsafe { Pin::new_unchecked(&*self.0) }
+    }
+}
+
+impl<T: ?Sized> Deref for PinMut<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.0 }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for PinMut<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PinMut")
+        .field(&self.deref())
+        .finish()
+    }
+}
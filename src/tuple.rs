@@ -0,0 +1,476 @@
+//! Helper traits for tuples, used by `LifeRef` methods taking a const index or extending past 16 elements.
+//!
+//! Implemented for tuples of up to 16 elements. Using an index which is out of range is a compile error.
+use core::fmt;
+use crate::{AsStdRefs, BorrowFields, CopyOut, Visit};
+use crate::builder::{sealed::Sealed, RefOnly};
+#[cfg(feature = "alloc")]
+use crate::ToOwnedView;
+
+/// Helper trait to allow referencing an element of the tuple at position `I`.
+pub trait Get<const I: usize> {
+    /// Element type at position `I`.
+    type Item;
+    /// Get reference to the element at position `I`.
+    fn get(&self) -> &Self::Item;
+}
+
+/// Helper trait to allow replacing an element of the tuple at position `I`.
+pub trait Map<const I: usize, U> {
+    /// Element type at position `I`.
+    type Item;
+    /// Tuple with the element at position `I` replaced by `U`.
+    type Output;
+    /// Transform the element at position `I` leaving the rest untouched.
+    fn map<F: FnOnce(Self::Item) -> U>(self, f: F) -> Self::Output;
+}
+
+/// Helper trait to allow removing an element of the tuple at position `I`.
+pub trait Remove<const I: usize> {
+    /// Element type at position `I`.
+    type Item;
+    /// Tuple of the remaining elements.
+    type Rest;
+    /// Split the tuple into the remaining elements (`Rest`) and the element at position `I` (`Item`).
+    fn remove(self) -> (Self::Rest, Self::Item);
+}
+
+/// Helper trait to allow splitting the tuple at position `I`.
+pub trait SplitAt<const I: usize> {
+    /// Tuple of the first `I` elements.
+    type Left;
+    /// Tuple of the remaining elements.
+    type Right;
+    /// Split the tuple into the first `I` elements (`Left`) and the rest (`Right`).
+    fn split_at(self) -> (Self::Left, Self::Right);
+}
+
+/// Helper trait to allow exchanging elements of the tuple at positions `I` and `J`.
+pub trait Swap<const I: usize, const J: usize> {
+    /// Tuple with types at positions `I` and `J` exchanged.
+    type Output;
+    /// Exchange elements at positions `I` and `J`. Same position leaves the tuple untouched.
+    fn swap(self) -> Self::Output;
+}
+
+/// Helper trait to allow appending to the tuple.
+///
+/// Same as `tuple_utils::Append`, except that appending to `()` produces 1-tuple rather than bare element.
+#[diagnostic::on_unimplemented(
+    message = "borrow_as supports at most 16 chained borrows; group with wrap_life to nest further",
+    label = "`{Self}` is not a tuple of fewer than 16 elements",
+    note = "use `add_ref_grouped` or `add_mut_grouped` to nest full tuple automatically",
+)]
+pub trait Append<T> {
+    /// Tuple extended by `T`.
+    type Output;
+    /// Append `T` onto the end of the tuple.
+    fn append(self, other: T) -> Self::Output;
+}
+
+/// Helper trait to allow appending to the tuple past 16 elements.
+///
+/// Behaves as `tuple_utils::Append` for tuples shorter than 16 elements,
+/// while full tuple is nested as the first element of a new 2-tuple.
+pub trait AppendGrouped<T> {
+    /// Tuple extended by `T`.
+    type Output;
+    /// Append `T` onto the end of the tuple, nesting it first if it's full.
+    fn append_grouped(self, other: T) -> Self::Output;
+}
+
+/// Helper trait to allow appending `N` elements of the same type at once.
+///
+/// Implemented as long as the result has up to 16 elements.
+pub trait AppendArray<T, const N: usize> {
+    /// Tuple extended by `N` elements of type `T`.
+    type Output;
+    /// Append elements of the array onto the end of the tuple in order.
+    fn append_array(self, other: [T; N]) -> Self::Output;
+}
+
+/// Helper trait to allow unnesting the tuple whose first element is a tuple itself, as produced by `add_life` after `wrap_life`.
+///
+/// Implemented as long as the result has up to 16 elements.
+pub trait Flatten {
+    /// Tuple with elements of the first one spliced in its place.
+    type Output;
+    /// Splice elements of the first tuple in its place.
+    fn flatten(self) -> Self::Output;
+}
+
+/// Helper trait exposing number of elements of the tuple at compile time.
+pub trait TupleLen {
+    /// Number of elements.
+    const LEN: usize;
+}
+
+/// Helper trait to display every element of the tuple, used by `Display` of `LifeRef`.
+pub trait DisplayElements {
+    /// Writes elements separated by commas inside braces.
+    fn fmt_elements(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Helper trait for tuples of `N` elements of the same type.
+pub trait Homogeneous<const N: usize> {
+    /// Type of every element.
+    type Item;
+    /// Get array of references to every element.
+    fn each_ref(&self) -> [&Self::Item; N];
+    /// Convert the tuple into array.
+    fn into_array(self) -> [Self::Item; N];
+}
+
+macro_rules! index_impl {
+    ([$($b:ident,)*], [], [$i:tt, $($idx:tt,)*]) => {
+        impl<$($b,)*> SplitAt<$i> for ($($b,)*) {
+            type Left = ($($b,)*);
+            type Right = ();
+
+            #[inline]
+            fn split_at(self) -> (Self::Left, Self::Right) {
+                (self, ())
+            }
+        }
+    };
+    ([$($b:ident,)*], [$t:ident, $($a:ident,)*], [$i:tt, $($idx:tt,)*]) => {
+        impl<$($b,)* $t, $($a,)*> Get<$i> for ($($b,)* $t, $($a,)*) {
+            type Item = $t;
+
+            #[inline]
+            fn get(&self) -> &$t {
+                &self.$i
+            }
+        }
+
+        impl<$($b,)* $t, $($a,)* U> Map<$i, U> for ($($b,)* $t, $($a,)*) {
+            type Item = $t;
+            type Output = ($($b,)* U, $($a,)*);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn map<F: FnOnce($t) -> U>(self, f: F) -> Self::Output {
+                let ($($b,)* $t, $($a,)*) = self;
+                ($($b,)* f($t), $($a,)*)
+            }
+        }
+
+        impl<$($b,)* $t, $($a,)*> Remove<$i> for ($($b,)* $t, $($a,)*) {
+            type Item = $t;
+            type Rest = ($($b,)* $($a,)*);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn remove(self) -> (Self::Rest, Self::Item) {
+                let ($($b,)* $t, $($a,)*) = self;
+                (($($b,)* $($a,)*), $t)
+            }
+        }
+
+        impl<$($b,)* $t, $($a,)*> SplitAt<$i> for ($($b,)* $t, $($a,)*) {
+            type Left = ($($b,)*);
+            type Right = ($t, $($a,)*);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn split_at(self) -> (Self::Left, Self::Right) {
+                let ($($b,)* $t, $($a,)*) = self;
+                (($($b,)*), ($t, $($a,)*))
+            }
+        }
+
+        index_impl!([$($b,)* $t,], [$($a,)*], [$($idx,)*]);
+    };
+}
+
+macro_rules! swap_impl {
+    ([$($b:ident,)*], [], [$($idx:tt,)*]) => {};
+    ([$($b:ident,)*], [$t:ident, $($rest:ident,)*], [$i:tt, $($idx:tt,)*]) => {
+        impl<$($b,)* $t, $($rest,)*> Swap<$i, $i> for ($($b,)* $t, $($rest,)*) {
+            type Output = Self;
+
+            #[inline]
+            fn swap(self) -> Self {
+                self
+            }
+        }
+
+        swap_impl!([$($b,)*], $t, $i, [], [$($rest,)*], [$($idx,)*]);
+        swap_impl!([$($b,)* $t,], [$($rest,)*], [$($idx,)*]);
+    };
+    ([$($b:ident,)*], $t:ident, $i:tt, [$($m:ident,)*], [], [$($idx:tt,)*]) => {};
+    ([$($b:ident,)*], $t:ident, $i:tt, [$($m:ident,)*], [$u:ident, $($a:ident,)*], [$j:tt, $($idx:tt,)*]) => {
+        impl<$($b,)* $t, $($m,)* $u, $($a,)*> Swap<$i, $j> for ($($b,)* $t, $($m,)* $u, $($a,)*) {
+            type Output = ($($b,)* $u, $($m,)* $t, $($a,)*);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn swap(self) -> Self::Output {
+                let ($($b,)* $t, $($m,)* $u, $($a,)*) = self;
+                ($($b,)* $u, $($m,)* $t, $($a,)*)
+            }
+        }
+
+        impl<$($b,)* $t, $($m,)* $u, $($a,)*> Swap<$j, $i> for ($($b,)* $t, $($m,)* $u, $($a,)*) {
+            type Output = ($($b,)* $u, $($m,)* $t, $($a,)*);
+
+            #[inline]
+            fn swap(self) -> Self::Output {
+                Swap::<$i, $j>::swap(self)
+            }
+        }
+
+        swap_impl!([$($b,)*], $t, $i, [$($m,)* $u,], [$($a,)*], [$($idx,)*]);
+    };
+}
+
+macro_rules! flatten_impl {
+    ([$($a:ident,)*], [$($b:ident,)*]) => {
+        impl<$($a,)* $($b,)*> Flatten for (($($a,)*), $($b,)*) {
+            type Output = ($($a,)* $($b,)*);
+
+            #[inline]
+            #[allow(non_snake_case, clippy::unused_unit)]
+            fn flatten(self) -> Self::Output {
+                let (($($a,)*), $($b,)*) = self;
+                ($($a,)* $($b,)*)
+            }
+        }
+    };
+}
+
+macro_rules! flatten_right {
+    ([$($a:ident,)*], [$($b:ident,)*], [$p:ident, $($pool:ident,)*], [$_h:tt $($budget:tt)*]) => {
+        flatten_impl!([$($a,)*], [$($b,)*]);
+        flatten_right!([$($a,)*], [$($b,)* $p,], [$($pool,)*], [$($budget)*]);
+    };
+    ([$($a:ident,)*], [$($b:ident,)*], [$($pool:ident,)*], [$($budget:tt)*]) => {
+        flatten_impl!([$($a,)*], [$($b,)*]);
+    };
+}
+
+macro_rules! flatten_left {
+    ([$($a:ident,)*], [$p:ident, $($pool:ident,)*], [$_h:tt $($budget:tt)*]) => {
+        flatten_right!([$($a,)*], [], [B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11, B12, B13, B14,], [$_h $($budget)*]);
+        flatten_left!([$($a,)* $p,], [$($pool,)*], [$($budget)*]);
+    };
+    ([$($a:ident,)*], [$($pool:ident,)*], [$($budget:tt)*]) => {
+        flatten_right!([$($a,)*], [], [B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11, B12, B13, B14,], [$($budget)*]);
+    };
+}
+
+flatten_left!(
+    [],
+    [A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15,],
+    [_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _]
+);
+
+macro_rules! borrow_fields_impl {
+    ($(($t:ident, $i:tt),)*) => {
+        impl<$($t,)* V> BorrowFields<V> for ($($t,)*) where
+        $(V: Visit<$t>,)* {
+            #[inline]
+            #[allow(unused_variables)]
+            fn visit_fields(&self, visitor: &mut V) {
+                $(visitor.visit(stringify!($i), &self.$i);)*
+            }
+        }
+    };
+}
+
+macro_rules! to_owned_view_impl {
+    ($(($t:ident, $i:tt),)*) => {
+        #[cfg(feature = "alloc")]
+        impl<$($t: ToOwnedView,)*> ToOwnedView for ($($t,)*) {
+            type Owned = ($($t::Owned,)*);
+
+            #[inline]
+            #[allow(clippy::unused_unit)]
+            fn to_owned_view(&self) -> Self::Owned {
+                ($(self.$i.to_owned_view(),)*)
+            }
+        }
+    };
+}
+
+macro_rules! as_std_refs_impl {
+    ($(($t:ident, $i:tt),)*) => {
+        impl<'r, $($t: AsStdRefs<'r>,)*> AsStdRefs<'r> for ($($t,)*) {
+            type Output = ($($t::Output,)*);
+
+            #[inline]
+            #[allow(clippy::unused_unit)]
+            fn as_std_refs(&'r self) -> Self::Output {
+                ($(self.$i.as_std_refs(),)*)
+            }
+        }
+    };
+}
+
+macro_rules! append_array_impl {
+    ($prev:tt,) => {};
+    ($prev:tt, $n:tt, $($rest:tt,)*) => {
+        impl<T: Append<U>, U> AppendArray<U, $n> for T where
+        T::Output: AppendArray<U, $prev> {
+            type Output = <T::Output as AppendArray<U, $prev>>::Output;
+
+            #[inline]
+            fn append_array(self, other: [U; $n]) -> Self::Output {
+                let [x, rest @ ..] = other;
+                self.append(x).append_array(rest)
+            }
+        }
+
+        append_array_impl!($n, $($rest,)*);
+    };
+}
+
+impl<T, U> AppendArray<U, 0> for T {
+    type Output = T;
+
+    #[inline]
+    fn append_array(self, _: [U; 0]) -> T {
+        self
+    }
+}
+
+append_array_impl!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,);
+
+macro_rules! copy_out_impl {
+    ($(($t:ident, $i:tt),)*) => {
+        impl<$($t: CopyOut,)*> CopyOut for ($($t,)*) {
+            type Owned = ($($t::Owned,)*);
+
+            #[inline]
+            #[allow(clippy::unused_unit)]
+            fn copy_out(&self) -> Self::Owned {
+                ($(self.$i.copy_out(),)*)
+            }
+        }
+    };
+}
+
+macro_rules! display_elements_impl {
+    ($(($t:ident, $i:tt),)*) => {
+        impl<$($t: fmt::Display,)*> DisplayElements for ($($t,)*) {
+            #[allow(unused_mut)]
+            fn fmt_elements(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut sep = "{ ";
+                $(
+                    write!(f, "{}{}", sep, self.$i)?;
+                    sep = ", ";
+                )*
+                f.write_str(if sep == "{ " { "{}" } else { " }" })
+            }
+        }
+    };
+}
+
+macro_rules! replace {
+    ($_t:tt, $($with:tt)*) => { $($with)* };
+}
+
+macro_rules! tuple_len_impl {
+    ($(($t:ident, $i:tt),)*) => {
+        impl<$($t,)*> TupleLen for ($($t,)*) {
+            const LEN: usize = 0 $(+ replace!($t, 1))*;
+        }
+    };
+}
+
+macro_rules! homogeneous_impl {
+    () => {};
+    ($(($t:ident, $i:tt),)+) => {
+        impl<T> Homogeneous<{ 0 $(+ replace!($t, 1))+ }> for ($(replace!($t, T),)+) {
+            type Item = T;
+
+            #[inline]
+            fn each_ref(&self) -> [&T; 0 $(+ replace!($t, 1))+] {
+                [$(&self.$i,)+]
+            }
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn into_array(self) -> [T; 0 $(+ replace!($t, 1))+] {
+                let ($($t,)+) = self;
+                [$($t,)+]
+            }
+        }
+    };
+}
+
+macro_rules! ref_only_impl {
+    ($(($t:ident, $i:tt),)*) => {
+        impl<$($t: RefOnly,)*> Sealed for ($($t,)*) {}
+
+        impl<$($t: RefOnly,)*> RefOnly for ($($t,)*) {}
+    };
+}
+
+macro_rules! for_each_arity {
+    ([$(($acc:ident, $ai:tt),)*], []) => {
+        index_impl!([], [$($acc,)*], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,]);
+        swap_impl!([], [$($acc,)*], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,]);
+        borrow_fields_impl!($(($acc, $ai),)*);
+        to_owned_view_impl!($(($acc, $ai),)*);
+        as_std_refs_impl!($(($acc, $ai),)*);
+        copy_out_impl!($(($acc, $ai),)*);
+        display_elements_impl!($(($acc, $ai),)*);
+        homogeneous_impl!($(($acc, $ai),)*);
+        tuple_len_impl!($(($acc, $ai),)*);
+        ref_only_impl!($(($acc, $ai),)*);
+
+        impl<$($acc,)* U> AppendGrouped<U> for ($($acc,)*) {
+            type Output = (Self, U);
+
+            #[inline]
+            fn append_grouped(self, x: U) -> Self::Output {
+                (self, x)
+            }
+        }
+    };
+    ([$(($acc:ident, $ai:tt),)*], [($arg0:ident, $i0:tt), $(($arg:ident, $i:tt),)*]) => {
+        index_impl!([], [$($acc,)*], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,]);
+        swap_impl!([], [$($acc,)*], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,]);
+        borrow_fields_impl!($(($acc, $ai),)*);
+        to_owned_view_impl!($(($acc, $ai),)*);
+        as_std_refs_impl!($(($acc, $ai),)*);
+        copy_out_impl!($(($acc, $ai),)*);
+        display_elements_impl!($(($acc, $ai),)*);
+        homogeneous_impl!($(($acc, $ai),)*);
+        tuple_len_impl!($(($acc, $ai),)*);
+        ref_only_impl!($(($acc, $ai),)*);
+
+        impl<$($acc,)* U> Append<U> for ($($acc,)*) {
+            type Output = ($($acc,)* U,);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn append(self, x: U) -> Self::Output {
+                let ($($acc,)*) = self;
+                ($($acc,)* x,)
+            }
+        }
+
+        impl<$($acc,)* U> AppendGrouped<U> for ($($acc,)*) {
+            type Output = ($($acc,)* U,);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn append_grouped(self, x: U) -> Self::Output {
+                let ($($acc,)*) = self;
+                ($($acc,)* x,)
+            }
+        }
+
+        for_each_arity!([$(($acc, $ai),)* ($arg0, $i0),], [$(($arg, $i),)*]);
+    };
+}
+
+for_each_arity! {
+    [],
+    [
+        (T0, 0), (T1, 1), (T2, 2), (T3, 3), (T4, 4), (T5, 5), (T6, 6), (T7, 7),
+        (T8, 8), (T9, 9), (T10, 10), (T11, 11), (T12, 12), (T13, 13), (T14, 14), (T15, 15),
+    ]
+}
@@ -0,0 +1,131 @@
+//! Soundness checks of core operations, run under Miri with `cargo +nightly miri test --test miri`.
+//! Miri tracks provenance of every pointer behind `Ref` and `Mut`, reporting any dereference past the end of its source.
+#![cfg(miri)]
+use borrow_as::builder::LifeRefBuilder;
+use borrow_as::*;
+
+struct View {
+    s: Ref<str>,
+    v: Mut<[u32]>,
+    n: Mut<u32>,
+}
+
+#[test]
+fn wrap_ref_reads() {
+    let s = String::from("Referenced");
+    let r = LifeRef::wrap_ref(s.as_str());
+    assert_eq!(r.0.len(), 10);
+    assert_eq!(r.0, "Referenced");
+}
+
+#[test]
+fn wrap_mut_writes() {
+    let mut s = String::from("Old");
+    let r = LifeRef::wrap_mut(&mut s);
+    let old = r.0.replace(String::from("New"));
+    assert_eq!(old, "Old");
+    assert_eq!(s, "New");
+}
+
+#[test]
+fn empty_chains() {
+    let a = 1;
+    let mut b = 2;
+    let r = LifeRef::empty().add_ref(&a).add_mut(&mut b);
+    r.1.set(*r.0);
+    assert_eq!(b, 1);
+}
+
+#[test]
+fn add_mut_disjoint_fields() {
+    let mut t = (1, 2);
+    let r = LifeRef::wrap_mut(&mut t.0).add_mut(&mut t.1);
+    r.0.swap(&r.1);
+    r.1.set(r.1.get() * 10);
+    assert_eq!(t, (2, 10));
+}
+
+#[test]
+fn add_mut_checked_disjoint_halves() {
+    let mut a = [1, 2, 3, 4];
+    let (lo, hi) = a.split_at_mut(2);
+    let r = LifeRef::wrap_mut(lo).add_mut_checked(hi);
+    r.0.iter_muts().for_each(|x| x.set(0));
+    r.1.fill(9);
+    assert_eq!(a, [0, 0, 9, 9]);
+}
+
+#[test]
+fn map_life_into_struct() {
+    let s = String::from("Referenced");
+    let mut v = vec![1, 2, 3];
+    let mut n = 0;
+    let view = LifeRef::
+        wrap_ref(s.as_str())
+        .add_mut(v.as_mut_slice())
+        .add_mut(&mut n)
+        .map_life(|(s, v, n)| View { s, v, n });
+    view.v.iter_muts().for_each(|x| view.n.set(view.n.get() + x.get()));
+    view.v.slice(1..).copy_from_slice(&[view.s.len() as u32; 2]);
+    assert_eq!(v, [1, 10, 10]);
+    assert_eq!(n, 6);
+}
+
+#[test]
+fn split_at_mut_recursion() {
+    fn fill(s: &Mut<[u32]>, from: u32) {
+        let len = s.iter_muts().len();
+        if len == 1 {
+            s.fill(from);
+        }
+        else if len > 1 {
+            let halves = s.split_at_mut(len / 2);
+            fill(&halves.0, from);
+            fill(&halves.1, from + (len / 2) as u32);
+        }
+    }
+
+    let mut v = [0; 7];
+    fill(&LifeRef::wrap_mut(&mut v[..]).0, 0);
+    assert_eq!(v, [0, 1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn wrap_ref_array_iterates() {
+    let (a, b, c) = (1, 2, 3);
+    let r = LifeRef::wrap_ref_array([&a, &b, &c]);
+    assert_eq!(r.into_iter().map(|x| *x).sum::<i32>(), 6);
+}
+
+#[test]
+fn builder_round_trip() {
+    let s = String::from("Referenced");
+    let mut n = 0u32;
+    let r = LifeRefBuilder::<2>::new()
+        .add_ref(&s)
+        .add_mut(&mut n)
+        .map_life(|[s, n]| unsafe { (s.into_ref::<String>(), n.into_mut::<u32>()) });
+    r.1.set(r.0.len() as u32);
+    assert_eq!(n, 10);
+}
+
+#[test]
+fn update_in_place() {
+    let mut v = vec![1];
+    let r = LifeRef::wrap_mut(&mut v);
+    unsafe { r.0.update(|v| v.push(2)) };
+    assert_eq!(v, [1, 2]);
+}
+
+/// `Ref` carries no lifetime, so it can escape its `LifeRef` through `into_inner` and dangle.
+/// Miri reports use-after-free here.
+#[test]
+#[ignore = "known misuse: Ref outlives its source after into_inner"]
+fn ref_outlives_source() {
+    let r = {
+        let s = String::from("Dropped");
+        let (r,) = unsafe { LifeRef::wrap_ref(s.as_str()).into_inner() };
+        r
+    };
+    assert_eq!(r.len(), 7);
+}
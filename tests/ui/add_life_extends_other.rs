@@ -0,0 +1,10 @@
+use borrow_as::{LifeRef, Ref};
+
+fn join<'long, 'short>(
+    a: LifeRef<'long, (Ref<i32>,)>,
+    b: LifeRef<'short, Ref<i32>>,
+) -> LifeRef<'long, (Ref<i32>, Ref<i32>)> {
+    a.add_life(b)
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use borrow_as::{LifeRef, Ref};
+
+fn join<'long, 'short>(
+    a: LifeRef<'short, (Ref<i32>,)>,
+    b: LifeRef<'long, Ref<i32>>,
+) -> LifeRef<'long, (Ref<i32>, Ref<i32>)> {
+    a.add_life_longer(b)
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use borrow_as::LifeRef;
+
+fn main() {
+    let x = 0;
+    let full = LifeRef::from((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15));
+    let _ = full.add_ref(&x);
+}
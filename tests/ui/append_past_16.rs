@@ -0,0 +1,9 @@
+use borrow_as::tuple::Append;
+use borrow_as::Ref;
+
+fn appendable<T: Append<Ref<i32>>>(_: &T) {}
+
+fn main() {
+    let full = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    appendable(&full);
+}
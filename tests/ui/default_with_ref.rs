@@ -0,0 +1,6 @@
+use borrow_as::{LifeRef, Ref};
+
+fn main() {
+    let r = LifeRef::<(Ref<i32>,)>::default();
+    assert_eq!(r.0, &0);
+}
@@ -0,0 +1,9 @@
+use borrow_as::{LifeRef, Ref};
+
+fn dangling<'a>() -> LifeRef<'a, (Ref<i32>,)> {
+    Default::default()
+}
+
+fn main() {
+    assert_eq!(dangling().0, &0);
+}
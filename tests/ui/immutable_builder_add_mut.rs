@@ -0,0 +1,6 @@
+use borrow_as::builder::ImmutableBuilder;
+
+fn main() {
+    let (a, mut b) = (1, 2);
+    let _ = ImmutableBuilder::wrap_ref(&a).add_mut(&mut b);
+}
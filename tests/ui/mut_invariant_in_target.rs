@@ -0,0 +1,15 @@
+use borrow_as::{LifeRef, Mut};
+
+fn shorten<'a, 'short>(r: LifeRef<'a, (Mut<&'static str>,)>) -> LifeRef<'a, (Mut<&'short str>,)> {
+    r
+}
+
+fn main() {
+    let mut s: &'static str = "static";
+    {
+        let local = String::from("local");
+        let r = shorten(LifeRef::wrap_mut(&mut s));
+        r.0.set(&local);
+    }
+    println!("{}", s);
+}
@@ -0,0 +1,13 @@
+use borrow_as::LifeRef;
+
+struct S {
+    a: i32,
+    b: i32,
+}
+
+fn main() {
+    let mut s = S { a: 1, b: 2 };
+    let r = LifeRef::wrap_ref(&s.a).add_mut(&mut s.a);
+    r.1.set(*r.0);
+    let _ = s.b;
+}
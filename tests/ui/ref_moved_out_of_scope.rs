@@ -0,0 +1,9 @@
+use borrow_as::{LifeRef, Ref};
+
+fn main() {
+    let e: Ref<String> = {
+        let s = String::new();
+        LifeRef::wrap_ref(&s).0
+    };
+    let _ = e.len();
+}
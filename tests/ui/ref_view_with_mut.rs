@@ -0,0 +1,6 @@
+use borrow_as::builder::RefView;
+use borrow_as::{Mut, Ref};
+
+fn view(_: RefView<'_, (Ref<str>, Mut<u32>)>) {}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use borrow_as::LifeRef;
+
+fn main() {
+    let n = 0;
+    let (_, e) = {
+        let s = String::new();
+        LifeRef::wrap_ref(&n).add_ref(&s).remove::<1>()
+    };
+    let _ = e.len();
+}
@@ -0,0 +1,10 @@
+use borrow_as::LifeRef;
+
+fn main() {
+    let e = {
+        let s = String::from(" Dropped ");
+        let r = LifeRef::wrap_ref(s.as_str());
+        r.0.trim()
+    };
+    let _ = e.len();
+}